@@ -0,0 +1,71 @@
+use permute_mmo_rs::generation::{spawn_generator, ShinyRollProfile, SpawnType};
+use permute_mmo_rs::permuter;
+use permute_mmo_rs::util::SpawnInfo;
+use permute_mmo_rs::Xoroshiro;
+use std::time::Instant;
+
+const TABLE: u64 = 0x7FA3A1DE69BD271E;
+const BONUS_TABLE: u64 = 0x44182B854CD3745D;
+const SEEDS: usize = 200;
+const SPAWNS: usize = 200_000;
+
+fn main() {
+    let profile = ShinyRollProfile::default();
+    let mut rng = Xoroshiro::new(0);
+    let start = Instant::now();
+    let mut shiny = 0;
+    for i in 0..SPAWNS {
+        let seed = rng.next_u64();
+        if let Some(entity) =
+            spawn_generator::generate(0, i, seed, seed, TABLE, SpawnType::MMO, &profile, false)
+        {
+            shiny += entity.is_shiny as usize;
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "generate: {SPAWNS} spawns in {elapsed:?} ({:?}/spawn, {shiny} shiny)",
+        elapsed / SPAWNS as u32
+    );
+
+    // Same spawns with the table resolved once up front, as the permuter does.
+    let encounter = spawn_generator::get_spawn_table(TABLE);
+    let mut rng = Xoroshiro::new(0);
+    let start = Instant::now();
+    let mut shiny = 0;
+    for i in 0..SPAWNS {
+        let seed = rng.next_u64();
+        if let Some(entity) = spawn_generator::generate_from_table(
+            0,
+            i,
+            seed,
+            seed,
+            encounter,
+            SpawnType::MMO,
+            &profile,
+            false,
+        ) {
+            shiny += entity.is_shiny as usize;
+        }
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "generate_from_table: {SPAWNS} spawns in {elapsed:?} ({:?}/spawn, {shiny} shiny)",
+        elapsed / SPAWNS as u32
+    );
+
+    let mut rng = Xoroshiro::new(1);
+    let start = Instant::now();
+    let mut results = 0;
+    for _ in 0..SEEDS {
+        let spawner = SpawnInfo::get_mmo(TABLE, 10, BONUS_TABLE, 6);
+        results += permuter::permute(spawner, rng.next_u64(), 15, None)
+            .results
+            .len();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "permute: {SEEDS} seeds in {elapsed:?} ({:?}/seed, {results} results)",
+        elapsed / SEEDS as u32
+    );
+}
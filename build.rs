@@ -0,0 +1,229 @@
+use pkhex_rs::game_strings::SPECIES_EN;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const SLOTS_PATH: &str = "resources/mmo_es.json";
+
+struct Slot {
+    rate: usize,
+    name: String,
+    is_alpha: bool,
+    level: Option<[usize; 2]>,
+    flawless_ivs: usize,
+    species: u16,
+    form: u16,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={SLOTS_PATH}");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let json = fs::read_to_string(SLOTS_PATH).expect("Failed to read encounter tables");
+    let obj: serde_json::Map<String, Value> =
+        serde_json::from_str(&json).expect("Malformed encounter table json");
+
+    let mut tables = Vec::with_capacity(obj.len());
+    let mut outbreaks = Vec::new();
+    for (key, value) in obj.iter() {
+        let slots = value
+            .as_array()
+            .unwrap_or_else(|| panic!("Table {key} is not a list of slots"))
+            .iter()
+            .enumerate()
+            .map(|(i, slot)| {
+                get_slot(slot).unwrap_or_else(|e| panic!("Invalid slot {i} in table {key}: {e}"))
+            })
+            .collect::<Vec<_>>();
+
+        // Tables keyed by a species name are regular mass outbreak tables.
+        if let Some(hash) = key
+            .strip_prefix("0x")
+            .and_then(|hex| u64::from_str_radix(hex, 16).ok())
+        {
+            tables.push((hash, slots));
+        } else {
+            let (species, form) =
+                get_species(key).unwrap_or_else(|e| panic!("Invalid table key {key}: {e}"));
+            outbreaks.push((get_outbreak_table(species, form), slots));
+        }
+    }
+    tables.sort_by_key(|(hash, _)| *hash);
+    outbreaks.sort_by_key(|(hash, _)| *hash);
+
+    let levels = get_outbreak_levels(&tables);
+    for slot in outbreaks.iter_mut().flat_map(|(_, slots)| slots.iter_mut()) {
+        if slot.level.is_none() {
+            slot.level = levels
+                .get(&(slot.species, slot.form, slot.is_alpha))
+                .copied();
+        }
+    }
+
+    let mut out = String::new();
+    write_tables(&mut out, "ENCOUNTER_TABLES", &tables);
+    write_tables(&mut out, "OUTBREAK_TABLES", &outbreaks);
+    write_levels(&mut out, &levels);
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("encounter_tables.rs");
+    fs::write(dest, out).expect("Failed to write encounter tables");
+}
+
+fn write_tables(out: &mut String, name: &str, tables: &[(u64, Vec<Slot>)]) {
+    writeln!(
+        out,
+        "pub static {name}: [EncounterTable; {}] = [",
+        tables.len()
+    )
+    .unwrap();
+    for (hash, slots) in tables.iter() {
+        writeln!(out, "    EncounterTable {{").unwrap();
+        writeln!(out, "        hash: {hash:#018X},").unwrap();
+        writeln!(out, "        slots: &[").unwrap();
+        for slot in slots {
+            let level = match slot.level {
+                Some([min, max]) => format!("Some([{min}, {max}])"),
+                None => "None".to_string(),
+            };
+            writeln!(
+                out,
+                "            SlotDetail {{ rate: {}, name: Cow::Borrowed({:?}), is_alpha: {}, level: {level}, flawless_ivs: {}, species: {}, form: {} }},",
+                slot.rate, slot.name, slot.is_alpha, slot.flawless_ivs, slot.species, slot.form
+            )
+            .unwrap();
+        }
+        writeln!(out, "        ],").unwrap();
+        writeln!(
+            out,
+            "        cumulative: &{:?},",
+            get_cumulative_sums(slots, false)
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "        cumulative_no_alpha: &{:?},",
+            get_cumulative_sums(slots, true)
+        )
+        .unwrap();
+        writeln!(out, "    }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+// Regular outbreak tables have no level ranges, so each species uses the widest range it has in
+// the massive mass outbreak tables, kept separate for alphas.
+fn get_outbreak_levels(tables: &[(u64, Vec<Slot>)]) -> BTreeMap<(u16, u16, bool), [usize; 2]> {
+    let mut levels: BTreeMap<(u16, u16, bool), [usize; 2]> = BTreeMap::new();
+    for slot in tables.iter().flat_map(|(_, slots)| slots.iter()) {
+        if let Some([min, max]) = slot.level {
+            levels
+                .entry((slot.species, slot.form, slot.is_alpha))
+                .and_modify(|level| *level = [level[0].min(min), level[1].max(max)])
+                .or_insert([min, max]);
+        }
+    }
+    levels
+}
+
+fn write_levels(out: &mut String, levels: &BTreeMap<(u16, u16, bool), [usize; 2]>) {
+    writeln!(
+        out,
+        "pub static OUTBREAK_LEVELS: [((u16, u16, bool), [usize; 2]); {}] = [",
+        levels.len()
+    )
+    .unwrap();
+    for ((species, form, is_alpha), [min, max]) in levels.iter() {
+        writeln!(
+            out,
+            "    (({species}, {form}, {is_alpha}), [{min}, {max}]),"
+        )
+        .unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+// Mirrors spawn_generator::get_outbreak_table.
+fn get_outbreak_table(species: u16, form: u16) -> u64 {
+    species as u64 | (form as u64) << 10
+}
+
+fn get_slot(value: &Value) -> Result<Slot, String> {
+    let rate = get_usize(value, "slot")?;
+    let flawless_ivs = get_usize(value, "ivs")?;
+    let is_alpha = value
+        .get("alpha")
+        .and_then(Value::as_bool)
+        .ok_or("missing alpha")?;
+    let name = value
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or("missing name")?
+        .to_string();
+    let level = match value.get("level") {
+        Some(level) => {
+            let level = level.as_array().ok_or("level is not a list")?;
+            match level.as_slice() {
+                [min, max] => Some([
+                    min.as_u64().ok_or("invalid min level")? as usize,
+                    max.as_u64().ok_or("invalid max level")? as usize,
+                ]),
+                _ => return Err("level must have two values".to_string()),
+            }
+        }
+        None => None,
+    };
+    let (species, form) = get_species(&name)?;
+    Ok(Slot {
+        rate,
+        name,
+        is_alpha,
+        level,
+        flawless_ivs,
+        species,
+        form,
+    })
+}
+
+fn get_usize(value: &Value, key: &str) -> Result<usize, String> {
+    value
+        .get(key)
+        .and_then(Value::as_u64)
+        .map(|v| v as usize)
+        .ok_or(format!("missing {key}"))
+}
+
+// Mirrors SlotDetail::try_set_species.
+fn get_species(name: &str) -> Result<(u16, u16), String> {
+    let (species, form) = match name.rsplit_once('-') {
+        Some((species, form)) if form.parse::<u16>().is_ok() => (species, form.parse().unwrap()),
+        _ => (name, 0),
+    };
+
+    let species = match species {
+        "MimeJr." => "Mime Jr.",
+        "Mr.Mime" => "Mr. Mime",
+        species => species,
+    };
+
+    SPECIES_EN
+        .iter()
+        .position(|s| *s == species)
+        .map(|i| (i as u16, form))
+        .ok_or(format!("No species found for {name}"))
+}
+
+fn get_cumulative_sums(slots: &[Slot], no_alpha: bool) -> Vec<f32> {
+    let mut total = 0.0;
+    slots
+        .iter()
+        .map(|slot| {
+            if !(no_alpha && slot.is_alpha) {
+                total += slot.rate as f32;
+            }
+            total
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use crate::generation::spawn_generator::{self, SpeciesIndex};
+use crate::util::json_decoder::SlotDetail;
+
+#[derive(Debug)]
+pub struct EncounterTable {
+    pub hash: u64,
+    pub slots: &'static [SlotDetail],
+    pub cumulative: &'static [f32],
+    pub cumulative_no_alpha: &'static [f32],
+}
+
+impl EncounterTable {
+    // Built-in tables are generated as statics, so tables created at runtime are leaked to match.
+    pub fn new(hash: u64, slots: Vec<SlotDetail>) -> Self {
+        let cumulative = get_cumulative_sums(&slots, false);
+        let cumulative_no_alpha = get_cumulative_sums(&slots, true);
+        Self {
+            hash,
+            slots: Box::leak(slots.into_boxed_slice()),
+            cumulative: Box::leak(cumulative.into_boxed_slice()),
+            cumulative_no_alpha: Box::leak(cumulative_no_alpha.into_boxed_slice()),
+        }
+    }
+
+    pub fn get_cumulative(&self, no_alpha: bool) -> &'static [f32] {
+        if no_alpha {
+            self.cumulative_no_alpha
+        } else {
+            self.cumulative
+        }
+    }
+
+    pub fn get_rate_sum(&self, no_alpha: bool) -> f32 {
+        self.get_cumulative(no_alpha)
+            .last()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn get_slot_index(&self, slot_roll: f32, no_alpha: bool) -> Option<usize> {
+        self.slots
+            .iter()
+            .zip(self.get_cumulative(no_alpha))
+            .position(|(slot, total)| !(no_alpha && slot.is_alpha) && slot_roll <= *total)
+    }
+
+    pub fn get_slot(&self, slot_roll: f32, no_alpha: bool) -> Option<&'static SlotDetail> {
+        self.get_slot_index(slot_roll, no_alpha)
+            .map(|index| &self.slots[index])
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SlotProbability<'a> {
+    pub slot: &'a SlotDetail,
+    pub probability: f32,
+}
+
+impl SlotProbability<'_> {
+    pub fn get_percent(&self) -> f32 {
+        self.probability * 100.0
+    }
+}
+
+pub fn get_table(table: u64) -> Option<&'static [SlotDetail]> {
+    spawn_generator::get_table(table)
+}
+
+pub fn get_table_hashes() -> Vec<u64> {
+    spawn_generator::get_table_hashes()
+}
+
+pub fn get_cumulative_sums(slots: &[SlotDetail], no_alpha: bool) -> Vec<f32> {
+    let mut total = 0.0;
+    slots
+        .iter()
+        .map(|slot| {
+            if !(no_alpha && slot.is_alpha) {
+                total += slot.rate as f32;
+            }
+            total
+        })
+        .collect()
+}
+
+pub fn get_rate_sum(slots: &[SlotDetail], no_alpha: bool) -> f32 {
+    slots
+        .iter()
+        .filter(|slot| !(no_alpha && slot.is_alpha))
+        .map(|slot| slot.rate as f32)
+        .sum()
+}
+
+pub fn get_probabilities(slots: &[SlotDetail], no_alpha: bool) -> Vec<SlotProbability<'_>> {
+    let total = get_rate_sum(slots, no_alpha);
+    slots
+        .iter()
+        .filter(|slot| !(no_alpha && slot.is_alpha))
+        .map(|slot| SlotProbability {
+            slot,
+            probability: if total == 0.0 {
+                0.0
+            } else {
+                slot.rate as f32 / total
+            },
+        })
+        .collect()
+}
+
+pub fn get_encounter_table(table: u64) -> Option<&'static EncounterTable> {
+    spawn_generator::get_encounter_table(table)
+}
+
+pub fn get_table_probabilities(
+    table: u64,
+    no_alpha: bool,
+) -> Option<Vec<SlotProbability<'static>>> {
+    get_table(table).map(|slots| get_probabilities(slots, no_alpha))
+}
+
+pub fn get_tables_containing(species: u16, form: u16) -> Vec<u64> {
+    get_species_index()
+        .get(&(species, form))
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub fn get_tables_containing_species(species: u16) -> Vec<u64> {
+    let mut tables = get_species_index()
+        .iter()
+        .filter(|((s, _), _)| *s == species)
+        .flat_map(|(_, tables)| tables.iter().copied())
+        .collect::<Vec<u64>>();
+    tables.sort_unstable();
+    tables.dedup();
+    tables
+}
+
+pub fn get_species_index() -> SpeciesIndex {
+    spawn_generator::get_species_index()
+}
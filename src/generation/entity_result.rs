@@ -1,11 +1,18 @@
-use crate::util::behavior_util;
+use crate::generation::spawn_generator;
 use crate::util::json_decoder::SlotDetail;
-use pkhex_rs::game_strings::NATURES_EN;
+use crate::util::size_util::SizeClass;
+use crate::util::{behavior_util, form_util, size_util, stat_util};
+use crate::RngDraw;
+use pkhex_rs::game_strings::{ABILITIES_EN, MOVES_EN, NATURES_EN};
+use pkhex_rs::{personal_table, PersonalInfo};
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct EntityResult {
-    pub slot: SlotDetail,
+    pub table: u64,
+    pub slot_index: usize,
     pub ivs: [u8; 6],
+    pub flawless_ivs: usize,
     pub group_seed: u64,
     pub index: usize,
     pub slot_seed: u64,
@@ -28,9 +35,31 @@ pub struct EntityResult {
     pub nature: u8,
     pub height: u8,
     pub weight: u8,
+    pub alpha_move: u16,
+    pub moves: [u16; 4],
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<Vec<RngDraw>>,
 }
 
 impl EntityResult {
+    pub fn get_slot(&self) -> Option<&'static SlotDetail> {
+        if self.table == spawn_generator::NO_TABLE {
+            return None;
+        }
+        // Only outbreak tables can be made up on demand, other hashes may be from tables that
+        // aren't loaded.
+        let encounter = if spawn_generator::is_outbreak_table(self.table) {
+            spawn_generator::get_spawn_table(self.table)
+        } else {
+            spawn_generator::get_encounter_table(self.table)?
+        };
+        encounter.slots.get(self.slot_index)
+    }
+
+    pub fn get_name(&self) -> String {
+        form_util::get_display_name(self.species, self.form)
+    }
+
     pub fn is_oblivious(&self) -> bool {
         behavior_util::OBLIVIOUS.contains(&self.species)
     }
@@ -43,6 +72,52 @@ impl EntityResult {
         self.is_alpha || !(self.is_skittish() || self.is_oblivious())
     }
 
+    pub fn has_move(&self, move_id: u16) -> bool {
+        move_id != 0 && self.moves.contains(&move_id)
+    }
+
+    pub fn get_alpha_move_name(&self) -> &'static str {
+        MOVES_EN[self.alpha_move as usize]
+    }
+
+    pub fn get_ability_id(&self) -> u16 {
+        personal_table::LA
+            .get_form_entry(self.species as usize, self.form as usize)
+            .get_ability_at_index(self.ability as usize) as u16
+    }
+
+    pub fn get_ability_name(&self) -> &'static str {
+        ABILITIES_EN[self.get_ability_id() as usize]
+    }
+
+    pub fn has_ability(&self, ability_id: u16) -> bool {
+        self.get_ability_id() == ability_id
+    }
+
+    pub fn has_ability_name(&self, name: &str) -> bool {
+        self.get_ability_name().eq_ignore_ascii_case(name)
+    }
+
+    pub fn get_stats(&self) -> [u16; 6] {
+        stat_util::get_stats(self.species, self.form, self.level, self.nature, &self.ivs)
+    }
+
+    pub fn get_height_class(&self) -> SizeClass {
+        SizeClass::from(self.height)
+    }
+
+    pub fn get_weight_class(&self) -> SizeClass {
+        SizeClass::from(self.weight)
+    }
+
+    pub fn get_height_m(&self) -> f32 {
+        size_util::get_height_m(self.species, self.form, self.height)
+    }
+
+    pub fn get_weight_kg(&self) -> f32 {
+        size_util::get_weight_kg(self.species, self.form, self.height, self.weight)
+    }
+
     pub fn get_summary(&self) -> String {
         let shiny = self.get_shiny_str();
         let ivs = format!(
@@ -50,6 +125,7 @@ impl EntityResult {
             self.ivs[0], self.ivs[1], self.ivs[2], self.ivs[3], self.ivs[4], self.ivs[5]
         );
         let nature = NATURES_EN[self.nature as usize];
+        let ability = self.get_ability_name();
         let alpha = if self.is_alpha { "α-" } else { " " };
         let not_alpha = if !self.is_alpha { " -- NOT ALPHA" } else { "" };
         let gender = match self.gender {
@@ -59,8 +135,8 @@ impl EntityResult {
         };
 
         format!(
-            "{alpha}{}{gender}:{shiny}{ivs}{nature:<8}{not_alpha}",
-            self.slot.name
+            "{alpha}{}{gender}:{shiny}{ivs}{nature:<8}{ability:<14}{not_alpha}",
+            self.get_name()
         )
     }
 
@@ -68,22 +144,36 @@ impl EntityResult {
         let mut lines = Vec::with_capacity(15);
         let shiny = self.get_shiny_str();
         let alpha = if self.is_alpha { "α-" } else { " " };
-        lines.push(format!("{}{}{}", shiny, alpha, self.slot.name));
+        lines.push(format!("{}{}{}", shiny, alpha, self.get_name()));
 
         lines.push(format!("Group Seed: {:0>16X}", self.group_seed));
         lines.push(format!("Alpha Move Seed: {:0>16X}", self.alpha_seed));
         lines.push(format!("Slot Seed: {:0>16X}", self.slot_seed));
         lines.push(format!("Slot: {:.5}", self.slot_roll as f32));
-        lines.push(format!("Level: {}", self.level));
+        if self.level != 0 {
+            lines.push(format!("Level: {}", self.level));
+        } else {
+            lines.push(String::from("Level: Unknown"));
+        }
         lines.push(format!("Gen Seed: {:0>16X}", self.gen_seed));
         lines.push(format!("  EC: {:0>8X}", self.ec));
         lines.push(format!("  PID: {:0>8X}", self.pid));
-        lines.push(format!("  Flawless IVs: {}", self.slot.flawless_ivs));
+        lines.push(format!("  Flawless IVs: {}", self.flawless_ivs));
         lines.push(format!(
             "  IVs: {}",
             self.ivs.iter().map(|iv| iv.to_string()).collect::<String>()
         ));
-        lines.push(format!("  Ability: {}", self.ability));
+        if self.level != 0 {
+            lines.push(format!(
+                "  Stats: {}",
+                self.get_stats()
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<String>>()
+                    .join("/")
+            ));
+        }
+        lines.push(format!("  Ability: {}", self.get_ability_name()));
         lines.push(format!(
             "  Gender: {}",
             match self.gender {
@@ -93,11 +183,42 @@ impl EntityResult {
             }
         ));
         lines.push(format!("  Nature: {}", NATURES_EN[self.nature as usize]));
-        lines.push(format!("  {} | {}", self.height, self.weight));
+        lines.push(format!(
+            "  Height: {:.2} m ({}) [{}]",
+            self.get_height_m(),
+            self.get_height_class(),
+            self.height
+        ));
+        lines.push(format!(
+            "  Weight: {:.1} kg ({}) [{}]",
+            self.get_weight_kg(),
+            self.get_weight_class(),
+            self.weight
+        ));
+        if self.is_alpha {
+            lines.push(format!("  Alpha Move: {}", self.get_alpha_move_name()));
+        }
+        lines.push(format!(
+            "  Moves: {}",
+            self.moves
+                .iter()
+                .filter(|m| **m != 0)
+                .map(|m| MOVES_EN[*m as usize])
+                .collect::<Vec<&str>>()
+                .join(" / ")
+        ));
 
         lines
     }
 
+    pub fn get_trace_lines(&self) -> Vec<String> {
+        self.trace
+            .iter()
+            .flatten()
+            .map(|draw| format!("  {draw}"))
+            .collect()
+    }
+
     pub fn get_shiny_str(&self) -> String {
         if self.is_shiny {
             format!(
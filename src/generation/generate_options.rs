@@ -0,0 +1,53 @@
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GenerateOptions {
+    pub species: u16,
+    pub form: u16,
+    pub level: usize,
+    pub shiny_rolls: usize,
+    pub flawless_ivs: usize,
+    pub is_alpha: bool,
+    pub alpha_seed: u64,
+    // Uses the species' personal gender ratio when not set.
+    pub gender_ratio: Option<usize>,
+    // Records every RNG draw in EntityResult::trace.
+    pub trace: bool,
+}
+
+impl GenerateOptions {
+    pub fn new(species: u16, form: u16, level: usize) -> Self {
+        Self {
+            species,
+            form,
+            level,
+            shiny_rolls: 1,
+            ..Self::default()
+        }
+    }
+
+    pub fn alpha(mut self, alpha_seed: u64) -> Self {
+        self.is_alpha = true;
+        self.alpha_seed = alpha_seed;
+        self.flawless_ivs = self.flawless_ivs.max(3);
+        self
+    }
+
+    pub fn shiny_rolls(mut self, shiny_rolls: usize) -> Self {
+        self.shiny_rolls = shiny_rolls;
+        self
+    }
+
+    pub fn flawless_ivs(mut self, flawless_ivs: usize) -> Self {
+        self.flawless_ivs = flawless_ivs;
+        self
+    }
+
+    pub fn gender_ratio(mut self, gender_ratio: usize) -> Self {
+        self.gender_ratio = Some(gender_ratio);
+        self
+    }
+
+    pub fn trace(mut self) -> Self {
+        self.trace = true;
+        self
+    }
+}
@@ -1,6 +1,12 @@
+pub mod encounter_table;
 mod entity_result;
+mod generate_options;
+pub mod move_generator;
+mod shiny_roll_profile;
 pub mod spawn_generator;
 mod spawn_type;
 
 pub use entity_result::*;
+pub use generate_options::*;
+pub use shiny_roll_profile::*;
 pub use spawn_type::*;
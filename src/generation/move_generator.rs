@@ -0,0 +1,65 @@
+use crate::generation::EntityResult;
+use crate::Xoroshiro;
+use pkhex_rs::learnset::{LEVEL_UP_LA, MOVE_SHOP_LA};
+use pkhex_rs::personal_table;
+
+// Alphas know their alpha move in the first slot, followed by their most recently learned level up
+// moves, matching PKHeX. Results with an unknown level (0) have no level up moves, so they only
+// know the alpha move if they have one.
+pub fn set_moves(result: &mut EntityResult) {
+    let moves = get_moves(result.species, result.form, result.level);
+    if !result.is_alpha {
+        result.moves = moves;
+        return;
+    }
+
+    let alpha_move = get_alpha_move(result.alpha_seed, result.species, result.form, &moves);
+    result.alpha_move = alpha_move;
+    result.moves = if alpha_move == 0 {
+        moves
+    } else {
+        get_alpha_moves(alpha_move, &moves)
+    };
+}
+
+// The last four moves learned by level up at the given level, or none when the level is unknown.
+pub fn get_moves(species: u16, form: u16, level: usize) -> [u16; 4] {
+    if level == 0 {
+        return [0; 4];
+    }
+    let index = personal_table::LA.get_form_index(species as usize, form as usize);
+    LEVEL_UP_LA[index].get_encounter_moves(level)
+}
+
+// Picks uniformly from the move shop moves the species can learn and does not already know, using
+// a single draw from the alpha seed.
+pub fn get_alpha_move(alpha_seed: u64, species: u16, form: u16, moves: &[u16; 4]) -> u16 {
+    let personal = personal_table::LA.get_form_entry(species as usize, form as usize);
+    let candidates = || {
+        MOVE_SHOP_LA
+            .iter()
+            .enumerate()
+            .filter(move |(i, m)| personal.is_move_shop(*i) && !moves.contains(m))
+            .map(|(_, m)| *m)
+    };
+    let count = candidates().count();
+    if count == 0 {
+        return 0;
+    }
+
+    let mut rng = Xoroshiro::new(alpha_seed);
+    let index = rng.next_max(count as u64) as usize;
+    candidates().nth(index).unwrap()
+}
+
+// The alpha move takes the first slot and the most recent level up moves fill the rest, newest
+// first, so the oldest level up move is the one dropped when all four slots are taken.
+pub fn get_alpha_moves(alpha_move: u16, moves: &[u16; 4]) -> [u16; 4] {
+    let mut result = [0; 4];
+    result[0] = alpha_move;
+    let learned = moves.iter().rev().filter(|m| **m != 0 && **m != alpha_move);
+    for (slot, m) in result[1..].iter_mut().zip(learned) {
+        *slot = *m;
+    }
+    result
+}
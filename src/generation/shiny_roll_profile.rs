@@ -0,0 +1,84 @@
+use crate::generation::SpawnType;
+use pkhex_rs::game_strings::SPECIES_EN;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, Default, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum ResearchLevel {
+    None,
+    Ten,
+    #[default]
+    Perfect,
+}
+
+impl ResearchLevel {
+    pub fn bonus_rolls(&self) -> usize {
+        match self {
+            ResearchLevel::None => 0,
+            ResearchLevel::Ten => 1,
+            ResearchLevel::Perfect => 3,
+        }
+    }
+}
+
+pub trait ResearchSource {
+    fn get_research_level(&self, species: u16) -> ResearchLevel;
+    fn has_shiny_charm(&self) -> bool;
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShinyRollProfile {
+    pub default_level: ResearchLevel,
+    pub research: HashMap<u16, ResearchLevel>,
+    pub shiny_charm: bool,
+}
+
+impl Default for ShinyRollProfile {
+    fn default() -> Self {
+        ShinyRollProfile::new(ResearchLevel::Perfect, true)
+    }
+}
+
+impl ShinyRollProfile {
+    const BASE_ROLLS: usize = 1;
+    const SHINY_CHARM_ROLLS: usize = 3;
+
+    pub fn new(default_level: ResearchLevel, shiny_charm: bool) -> Self {
+        Self {
+            default_level,
+            research: HashMap::new(),
+            shiny_charm,
+        }
+    }
+
+    pub fn from_research(source: &impl ResearchSource) -> Self {
+        let mut profile = ShinyRollProfile::new(ResearchLevel::None, source.has_shiny_charm());
+        for species in 1..SPECIES_EN.len() as u16 {
+            let level = source.get_research_level(species);
+            if level != ResearchLevel::None {
+                profile.set_research(species, level);
+            }
+        }
+        profile
+    }
+
+    pub fn set_research(&mut self, species: u16, level: ResearchLevel) {
+        self.research.insert(species, level);
+    }
+
+    pub fn get_research(&self, species: u16) -> ResearchLevel {
+        *self.research.get(&species).unwrap_or(&self.default_level)
+    }
+
+    pub fn get_rolls(&self, species: u16, spawn_type: SpawnType) -> usize {
+        let charm = if self.shiny_charm {
+            ShinyRollProfile::SHINY_CHARM_ROLLS
+        } else {
+            0
+        };
+        ShinyRollProfile::BASE_ROLLS
+            + self.get_research(species).bonus_rolls()
+            + charm
+            + spawn_type.bonus_rolls()
+    }
+}
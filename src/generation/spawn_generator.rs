@@ -1,20 +1,185 @@
-use crate::generation::{EntityResult, SpawnType};
-use crate::util::json_decoder::{get_map, SlotDetail};
-use crate::Xoroshiro;
+use crate::generation::encounter_table::EncounterTable;
+use crate::generation::{
+    move_generator, EntityResult, GenerateOptions, ShinyRollProfile, SpawnType,
+};
+use crate::util::json_decoder::SlotDetail;
+#[cfg(feature = "runtime-tables")]
+use crate::util::json_decoder::{try_get_map, TableError};
+use crate::TracedRng;
 use lazy_static::lazy_static;
 use pkhex_rs::game_strings::SPECIES_EN;
 use pkhex_rs::{
-    personal_table, PersonalInfo, Species, RATIO_MAGIC_FEMALE, RATIO_MAGIC_GENDERLESS,
-    RATIO_MAGIC_MALE,
+    personal_table, PersonalInfo, RATIO_MAGIC_FEMALE, RATIO_MAGIC_GENDERLESS, RATIO_MAGIC_MALE,
 };
+use std::borrow::Cow;
 use std::collections::HashMap;
+#[cfg(feature = "runtime-tables")]
+use std::path::Path;
+use std::sync::{Arc, RwLock};
 
-const SLOTS_RAW: &str = include_str!("../../resources/mmo_es.json");
+// Generated by build.rs from resources/mmo_es.json, sorted by hash.
+include!(concat!(env!("OUT_DIR"), "/encounter_tables.rs"));
 
 lazy_static! {
-    pub static ref SLOT_MAP: HashMap<u64, Vec<SlotDetail>> = get_map(SLOTS_RAW);
+    static ref LOADED_MAP: RwLock<HashMap<u64, &'static EncounterTable>> =
+        RwLock::new(HashMap::new());
+    static ref OUTBREAK_MAP: RwLock<HashMap<u64, &'static EncounterTable>> =
+        RwLock::new(HashMap::new());
+    // Built on first use and dropped whenever tables are loaded.
+    static ref SPECIES_INDEX: RwLock<Option<SpeciesIndex>> = RwLock::new(None);
+}
+
+pub type SpeciesIndex = Arc<HashMap<(u16, u16), Vec<u64>>>;
+
+// Loaded tables are leaked so they can be borrowed for the rest of the program just like the
+// built-in ones. They are expected to be loaded once at startup.
+#[cfg(feature = "runtime-tables")]
+pub fn load_tables(json: &str) -> Result<Vec<u64>, TableError> {
+    let map = try_get_map(json)?;
+    let mut loaded = LOADED_MAP.write().unwrap();
+    let mut hashes = Vec::with_capacity(map.len());
+    for (hash, mut slots) in map {
+        if is_outbreak_table(hash) {
+            fill_outbreak_levels(&mut slots);
+        }
+        loaded.insert(hash, Box::leak(Box::new(EncounterTable::new(hash, slots))));
+        hashes.push(hash);
+    }
+    hashes.sort_unstable();
+    *SPECIES_INDEX.write().unwrap() = None;
+    Ok(hashes)
+}
+
+#[cfg(feature = "runtime-tables")]
+pub fn load_table_file(path: impl AsRef<Path>) -> Result<Vec<u64>, TableError> {
+    let path = path.as_ref();
+    let json = std::fs::read_to_string(path).map_err(|e| TableError::Io {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    load_tables(&json)
+}
+
+pub fn get_encounter_table(table: u64) -> Option<&'static EncounterTable> {
+    if let Some(encounter) = LOADED_MAP.read().unwrap().get(&table) {
+        return Some(encounter);
+    }
+    let tables = if is_outbreak_table(table) {
+        &OUTBREAK_TABLES[..]
+    } else {
+        &ENCOUNTER_TABLES[..]
+    };
+    tables
+        .binary_search_by_key(&table, |encounter| encounter.hash)
+        .ok()
+        .map(|index| &tables[index])
+}
+
+// Entities generated outside of an encounter table.
+pub const NO_TABLE: u64 = 0;
+
+// Regular outbreaks have no table hash, so the species and form are packed into the table instead.
+// Species fit in the low 10 bits and forms in the next 6, well below any real table hash.
+const OUTBREAK_FORM_SHIFT: u64 = 10;
+const OUTBREAK_TABLE_LIMIT: u64 = 1 << 16;
+
+pub fn get_outbreak_table(species: u16, form: u16) -> u64 {
+    species as u64 | (form as u64) << OUTBREAK_FORM_SHIFT
+}
+
+pub fn is_outbreak_table(table: u64) -> bool {
+    table < OUTBREAK_TABLE_LIMIT
+}
+
+// Regular outbreak tables have no level ranges of their own, so they use the range the species has
+// in the massive mass outbreak tables. Species that never appear there have no known level.
+pub fn get_outbreak_level(species: u16, form: u16, is_alpha: bool) -> Option<[usize; 2]> {
+    OUTBREAK_LEVELS
+        .binary_search_by_key(&(species, form, is_alpha), |(key, _)| *key)
+        .ok()
+        .map(|index| OUTBREAK_LEVELS[index].1)
+}
+
+#[cfg(feature = "runtime-tables")]
+fn fill_outbreak_levels(slots: &mut [SlotDetail]) {
+    for slot in slots.iter_mut().filter(|slot| slot.level.is_none()) {
+        slot.level = get_outbreak_level(slot.species, slot.form, slot.is_alpha);
+    }
+}
+
+// Outbreak species without a table still generate, using the same level ranges as bundled tables.
+pub fn get_spawn_table(table: u64) -> &'static EncounterTable {
+    if let Some(encounter) = get_encounter_table(table) {
+        return encounter;
+    }
+    assert!(
+        is_outbreak_table(table),
+        "No encounter table for {table:#018X}"
+    );
+
+    if let Some(encounter) = OUTBREAK_MAP.read().unwrap().get(&table) {
+        return encounter;
+    }
+    OUTBREAK_MAP
+        .write()
+        .unwrap()
+        .entry(table)
+        .or_insert_with(|| {
+            Box::leak(Box::new(EncounterTable::new(
+                table,
+                get_fake_outbreak(
+                    (table & ((1 << OUTBREAK_FORM_SHIFT) - 1)) as u16,
+                    (table >> OUTBREAK_FORM_SHIFT) as u16,
+                ),
+            )))
+        })
 }
 
+pub fn get_table(table: u64) -> Option<&'static [SlotDetail]> {
+    get_encounter_table(table).map(|encounter| encounter.slots)
+}
+
+pub fn get_table_hashes() -> Vec<u64> {
+    let mut hashes = ENCOUNTER_TABLES
+        .iter()
+        .map(|encounter| encounter.hash)
+        .collect::<Vec<u64>>();
+    hashes.extend(
+        LOADED_MAP
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|hash| !is_outbreak_table(**hash)),
+    );
+    hashes.sort_unstable();
+    hashes.dedup();
+    hashes
+}
+
+// Every table each species and form appears in, sorted by hash.
+pub fn get_species_index() -> SpeciesIndex {
+    if let Some(index) = SPECIES_INDEX.read().unwrap().as_ref() {
+        return index.clone();
+    }
+    SPECIES_INDEX
+        .write()
+        .unwrap()
+        .get_or_insert_with(|| {
+            let mut index: HashMap<(u16, u16), Vec<u64>> = HashMap::new();
+            for table in get_table_hashes() {
+                for slot in get_table(table).unwrap_or_default() {
+                    let tables = index.entry((slot.species, slot.form)).or_default();
+                    if !tables.contains(&table) {
+                        tables.push(table);
+                    }
+                }
+            }
+            Arc::new(index)
+        })
+        .clone()
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn generate(
     group_seed: u64,
     index: usize,
@@ -22,102 +187,186 @@ pub fn generate(
     alpha_seed: u64,
     table: u64,
     spawn_type: SpawnType,
+    profile: &ShinyRollProfile,
+    no_alpha: bool,
+) -> Option<EntityResult> {
+    generate_slot(
+        group_seed, index, seed, alpha_seed, table, spawn_type, profile, no_alpha, false,
+    )
+}
+
+// Same as generate, with every RNG draw recorded in EntityResult::trace.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_traced(
+    group_seed: u64,
+    index: usize,
+    seed: u64,
+    alpha_seed: u64,
+    table: u64,
+    spawn_type: SpawnType,
+    profile: &ShinyRollProfile,
+    no_alpha: bool,
+) -> Option<EntityResult> {
+    generate_slot(
+        group_seed, index, seed, alpha_seed, table, spawn_type, profile, no_alpha, true,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate_slot(
+    group_seed: u64,
+    index: usize,
+    seed: u64,
+    alpha_seed: u64,
+    table: u64,
+    spawn_type: SpawnType,
+    profile: &ShinyRollProfile,
     no_alpha: bool,
+    trace: bool,
 ) -> Option<EntityResult> {
-    let mut slot_rng = Xoroshiro::new(seed);
+    generate_from_encounter(
+        group_seed,
+        index,
+        seed,
+        alpha_seed,
+        get_spawn_table(table),
+        spawn_type,
+        profile,
+        no_alpha,
+        trace,
+    )
+}
 
-    let slots = get_slots(table);
+// Same as generate, for callers that resolved the table up front to skip the lookup per spawn.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_from_table(
+    group_seed: u64,
+    index: usize,
+    seed: u64,
+    alpha_seed: u64,
+    encounter: &EncounterTable,
+    spawn_type: SpawnType,
+    profile: &ShinyRollProfile,
+    no_alpha: bool,
+) -> Option<EntityResult> {
+    generate_from_encounter(
+        group_seed, index, seed, alpha_seed, encounter, spawn_type, profile, no_alpha, false,
+    )
+}
 
-    let slot_sum = get_slot_sum(&slots, no_alpha);
+#[allow(clippy::too_many_arguments)]
+fn generate_from_encounter(
+    group_seed: u64,
+    index: usize,
+    seed: u64,
+    alpha_seed: u64,
+    encounter: &EncounterTable,
+    spawn_type: SpawnType,
+    profile: &ShinyRollProfile,
+    no_alpha: bool,
+    trace: bool,
+) -> Option<EntityResult> {
+    let mut slot_rng = TracedRng::new(seed, trace);
+
+    let slot_sum = encounter.get_rate_sum(no_alpha);
     if slot_sum == 0.0 {
         return None;
     }
 
-    let slot_roll = slot_rng.next_f32(slot_sum, 0.0);
-    let slot = get_slot(&slots, slot_roll, no_alpha);
-    let gen_seed = slot_rng.next_u64();
-    let level = get_level(&slot, slot_rng);
-    let gt = personal_table::LA
-        .get_form_entry(slot.species as usize, slot.form as usize)
-        .get_gender();
+    let slot_roll = slot_rng.next_f32("slot roll", slot_sum, 0.0);
+    let slot_index = encounter
+        .get_slot_index(slot_roll, no_alpha)
+        .expect("Slot roll out of range of slot values");
+    let slot = &encounter.slots[slot_index];
+    let gen_seed = slot_rng.next_u64("gen seed", 0);
+    let level = get_level(slot, &mut slot_rng);
 
-    let shiny_rolls = spawn_type as usize;
-    let flawless_ivs = slot.flawless_ivs;
-    let mut result = EntityResult {
+    let options = GenerateOptions {
         species: slot.species,
         form: slot.form,
         level,
-        ec: 0,
-        fake_tid: 0,
-        pid: 0,
-        shiny_xor: 0,
-        roll_count_used: 0,
+        shiny_rolls: profile.get_rolls(slot.species, spawn_type),
+        flawless_ivs: slot.flawless_ivs,
         is_alpha: slot.is_alpha,
-        ability: 0,
-        gender: 0,
-        nature: 0,
-        height: 0,
-        group_seed,
-        index,
-        slot_seed: seed,
-        gen_seed,
         alpha_seed,
-        slot_roll,
-        ivs: [0; 6],
-        roll_count_allowed: 0,
-        is_shiny: false,
-        weight: 0,
-        slot,
+        gender_ratio: None,
+        trace,
     };
-
-    generate_pokemon(&mut result, gen_seed, shiny_rolls, flawless_ivs, gt);
-
+    let mut result = generate_from_seed(gen_seed, &options);
+    if let (Some(slot_draws), Some(draws)) = (slot_rng.draws, result.trace.as_mut()) {
+        draws.splice(0..0, slot_draws);
+    }
+    result.group_seed = group_seed;
+    result.index = index;
+    result.slot_seed = seed;
+    result.slot_roll = slot_roll;
+    result.table = encounter.hash;
+    result.slot_index = slot_index;
     Some(result)
 }
 
-fn get_slots(table: u64) -> Vec<SlotDetail> {
-    if table > 1000 {
-        SLOT_MAP.get(&table).unwrap().clone()
-    } else {
-        let species = table as u16;
-        get_fake_outbreak(species)
-    }
+pub fn generate_from_seed(gen_seed: u64, options: &GenerateOptions) -> EntityResult {
+    let gender_ratio = options.gender_ratio.unwrap_or_else(|| {
+        personal_table::LA
+            .get_form_entry(options.species as usize, options.form as usize)
+            .get_gender()
+    });
+
+    let mut result = EntityResult {
+        table: NO_TABLE,
+        species: options.species,
+        form: options.form,
+        level: options.level,
+        is_alpha: options.is_alpha,
+        gen_seed,
+        alpha_seed: options.alpha_seed,
+        ..EntityResult::default()
+    };
+
+    let mut rng = TracedRng::new(gen_seed, options.trace);
+    generate_pokemon_with(
+        &mut result,
+        &mut rng,
+        options.shiny_rolls,
+        options.flawless_ivs,
+        gender_ratio,
+    );
+    result.trace = rng.draws;
+    move_generator::set_moves(&mut result);
+
+    result
 }
 
-fn get_fake_outbreak(species: u16) -> Vec<SlotDetail> {
-    let mut name = SPECIES_EN[species as usize].to_string();
-    if species == Species::Basculin as u16 {
-        name = format!("{}-2", name);
-    }
+fn get_fake_outbreak(species: u16, form: u16) -> Vec<SlotDetail> {
+    let name = if form == 0 {
+        SPECIES_EN[species as usize].to_string()
+    } else {
+        format!("{}-{form}", SPECIES_EN[species as usize])
+    };
 
-    let mut value = vec![
+    vec![
         SlotDetail {
             rate: 100,
-            name: name.clone(),
+            name: name.clone().into(),
             is_alpha: false,
-            level: Some([0, 1]),
+            level: get_outbreak_level(species, form, false),
             flawless_ivs: 0,
-            species: 0,
-            form: 0,
+            species,
+            form,
         },
         SlotDetail {
             rate: 1,
-            name,
+            name: name.into(),
             is_alpha: true,
-            level: Some([0, 1]),
+            level: get_outbreak_level(species, form, true),
             flawless_ivs: 3,
-            species: 0,
-            form: 0,
+            species,
+            form,
         },
-    ];
-    for slot in value.iter_mut() {
-        slot.set_species();
-    }
-
-    value
+    ]
 }
 
-fn get_level(slot: &SlotDetail, mut slot_rng: Xoroshiro) -> usize {
+fn get_level(slot: &SlotDetail, slot_rng: &mut TracedRng) -> usize {
     let min = slot.min_level();
     let max = slot.max_level();
 
@@ -125,36 +374,11 @@ fn get_level(slot: &SlotDetail, mut slot_rng: Xoroshiro) -> usize {
     let delta = max - min;
 
     if delta != 0 {
-        level += slot_rng.next_max((delta + 1) as u64) as usize;
+        level += slot_rng.next_max("level", 0, (delta + 1) as u64) as usize;
     }
     level
 }
 
-fn get_slot_sum(slots: &[SlotDetail], no_alpha: bool) -> f32 {
-    let mut total = 0.0;
-    for slot in slots {
-        if no_alpha && slot.is_alpha {
-            continue;
-        }
-        total += slot.rate as f32;
-    }
-    total
-}
-
-fn get_slot(slots: &[SlotDetail], mut slot_roll: f32, no_alpha: bool) -> SlotDetail {
-    for slot in slots {
-        if no_alpha && slot.is_alpha {
-            continue;
-        }
-
-        slot_roll -= slot.rate as f32;
-        if slot_roll <= 0.0 {
-            return slot.clone();
-        }
-    }
-    panic!("Slot roll out of range of slot values")
-}
-
 pub fn generate_pokemon(
     result: &mut EntityResult,
     seed: u64,
@@ -162,15 +386,25 @@ pub fn generate_pokemon(
     flawless: usize,
     gender_ratio: usize,
 ) {
-    let mut rng = Xoroshiro::new(seed);
-    result.ec = rng.next_max(0xFFFFFFFF) as u32;
-    result.fake_tid = rng.next_max(0xFFFFFFFF) as u32;
+    let mut rng = TracedRng::new(seed, false);
+    generate_pokemon_with(result, &mut rng, shiny_rolls, flawless, gender_ratio);
+}
+
+fn generate_pokemon_with(
+    result: &mut EntityResult,
+    rng: &mut TracedRng,
+    shiny_rolls: usize,
+    flawless: usize,
+    gender_ratio: usize,
+) {
+    result.ec = rng.next_max("EC", 0, 0xFFFFFFFF) as u32;
+    result.fake_tid = rng.next_max("fake TID", 0, 0xFFFFFFFF) as u32;
 
     let mut pid;
     let mut ctr = 0;
     loop {
         ctr += 1;
-        pid = rng.next_max(0xFFFFFFFF) as u32;
+        pid = rng.next_max("PID", ctr, 0xFFFFFFFF) as u32;
         let shiny_xor = get_shiny_xor(pid, result.fake_tid);
         let is_shiny = shiny_xor < 16;
         result.is_shiny = is_shiny;
@@ -188,31 +422,32 @@ pub fn generate_pokemon(
         break;
     }
     result.pid = pid;
+    result.flawless_ivs = flawless;
     let ivs = &mut result.ivs;
 
-    for _ in 0..flawless {
-        let mut index = rng.next_max(6) as usize;
+    for i in 0..flawless {
+        let mut index = rng.next_max("flawless IV", i, 6) as usize;
         while ivs[index] != 0 {
-            index = rng.next_max(6) as usize;
+            index = rng.next_max("flawless IV", i, 6) as usize;
         }
 
         ivs[index] = 31;
     }
 
-    for iv in ivs.iter_mut() {
+    for (i, iv) in ivs.iter_mut().enumerate() {
         if *iv == 0 {
-            *iv = rng.next_max(32) as u8;
+            *iv = rng.next_max("IV", i, 32) as u8;
         }
     }
 
-    result.ability = rng.next_max(2) as u8;
+    result.ability = rng.next_max("ability", 0, 2) as u8;
 
     result.gender = match gender_ratio {
         i if i == RATIO_MAGIC_GENDERLESS => 2,
         i if i == RATIO_MAGIC_FEMALE => 1,
         i if i == RATIO_MAGIC_MALE => 0,
         _ => {
-            if (rng.next_max(253) as usize + 1) < gender_ratio {
+            if (rng.next_max("gender", 0, 253) as usize + 1) < gender_ratio {
                 1
             } else {
                 0
@@ -220,16 +455,16 @@ pub fn generate_pokemon(
         }
     };
 
-    result.nature = rng.next_max(25) as u8;
+    result.nature = rng.next_max("nature", 0, 25) as u8;
     result.height = if result.is_alpha {
         u8::MAX
     } else {
-        (rng.next_max(0x81) + rng.next_max(0x80)) as u8
+        (rng.next_max("height", 0, 0x81) + rng.next_max("height", 1, 0x80)) as u8
     };
     result.weight = if result.is_alpha {
         u8::MAX
     } else {
-        (rng.next_max(0x81) + rng.next_max(0x80)) as u8
+        (rng.next_max("weight", 0, 0x81) + rng.next_max("weight", 1, 0x80)) as u8
     };
 }
 
@@ -4,3 +4,9 @@ pub enum SpawnType {
     MMO = 7 + 12,
     Outbreak = 7 + 25,
 }
+
+impl SpawnType {
+    pub fn bonus_rolls(&self) -> usize {
+        *self as usize - SpawnType::Regular as usize
+    }
+}
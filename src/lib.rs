@@ -1,10 +1,14 @@
 pub mod generation;
 pub mod permutation;
 pub mod permuter;
+mod rng_trace;
+mod slot_state;
 mod spawn_state;
 pub mod structure;
 pub mod util;
 mod xorshift;
 
+pub use rng_trace::*;
+pub use slot_state::*;
 pub use spawn_state::*;
 pub use xorshift::*;
@@ -1,17 +1,37 @@
 use clap::Parser;
-use permute_mmo_rs::generation::EntityResult;
-use permute_mmo_rs::permutation::Advance;
+use permute_mmo_rs::generation::{spawn_generator, EntityResult, ShinyRollProfile};
+use permute_mmo_rs::permutation::{Advance, PermuteMeta};
 use permute_mmo_rs::permuter;
 use permute_mmo_rs::structure::{
     MassOutbreakSet8a, MassiveOutbreakArea8a, MassiveOutbreakSet8a, MassiveOutbreakSpawnerStatus,
 };
 use permute_mmo_rs::util::area_util::AREA_TABLE;
-use permute_mmo_rs::util::{area_util, get_summary, SpawnInfo};
-use pkhex_rs::game_strings::SPECIES_EN;
+use permute_mmo_rs::util::{
+    area_util, export_util, form_util, get_summary, outbreak_odds, save_util, SpawnInfo,
+    SPECIES_DICT,
+};
+use permute_mmo_rs::{SpawnState, Xoroshiro};
 use std::cell::RefCell;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use sysbot_rs::SysBotClient;
 
+// A state seen on the field, applied to the one spawner and wave it was seen at.
+#[derive(Copy, Clone)]
+struct FieldState {
+    state: SpawnState,
+    area: usize,
+    spawner: Option<usize>,
+    wave: usize,
+    seed: Option<u64>,
+}
+
+impl FieldState {
+    fn get_seed(&self, group_seed: u64) -> u64 {
+        self.seed.unwrap_or(group_seed)
+    }
+}
+
 fn satisfy_criteria(entity: &EntityResult, _advances: &[Advance]) -> bool {
     entity.is_shiny
 }
@@ -27,11 +47,126 @@ struct PermuteMmo {
         default_value_t = 6000
     )]
     port: u16,
+    #[arg(
+        long,
+        short,
+        help = "Current field state to permute from, written as slot glyphs (aABO~X?) followed by the remaining count, e.g. \"aAB~ 5\""
+    )]
+    state: Option<SpawnState>,
+    #[arg(
+        long,
+        help = "Area the --state was seen in, numbered from 1 as printed when permuting"
+    )]
+    area: Option<usize>,
+    #[arg(
+        long,
+        help = "Massive mass outbreak spawner in --area the --state was seen at, numbered from 1. Leave out for a regular mass outbreak"
+    )]
+    spawner: Option<usize>,
+    #[arg(
+        long,
+        help = "Wave the --state was seen in, 1 for the first wave and 2 for the bonus wave",
+        default_value_t = 1
+    )]
+    wave: usize,
+    #[arg(
+        long,
+        help = "Group seed at the time of --state. Required for later waves, defaults to the spawner's seed"
+    )]
+    seed: Option<u64>,
+    #[arg(
+        long,
+        help = "Decrypted Legends: Arceus save file to read Pokédex research and Shiny Charm from. Defaults to perfect research with the Shiny Charm"
+    )]
+    save: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "Additional encounter table json files. Tables with the same hash as a built-in table replace it"
+    )]
+    tables: Vec<PathBuf>,
+    #[arg(
+        long,
+        help = "Directory to export every matching result to as a .pa8 file"
+    )]
+    export: Option<PathBuf>,
+    #[arg(
+        long,
+        num_args = 0..=1,
+        default_missing_value = "500",
+        help = "Estimate the odds of every outbreak from this many random seeds instead of permuting, 500 if no count is given. Each seed is permuted, so expect about a second per outbreak for every 200 seeds"
+    )]
+    odds: Option<usize>,
+    #[arg(
+        long,
+        help = "Species to report odds of a shiny for with --odds, e.g. \"Growlithe\""
+    )]
+    target: Option<String>,
 }
 
 fn main() {
     let args: PermuteMmo = PermuteMmo::parse();
 
+    for path in args.tables.iter() {
+        match spawn_generator::load_table_file(path) {
+            Ok(hashes) => println!("Loaded {} tables from {}", hashes.len(), path.display()),
+            Err(e) => {
+                println!("Failed to load tables: {e}");
+                return;
+            }
+        }
+    }
+
+    let profile = if let Some(path) = args.save.as_ref() {
+        let Ok(data) = std::fs::read(path) else {
+            println!("Failed to read save file {}!", path.display());
+            return;
+        };
+        match save_util::get_profile(data) {
+            Ok(profile) => profile,
+            Err(e) => {
+                println!("Failed to load save file {}: {e}", path.display());
+                return;
+            }
+        }
+    } else {
+        ShinyRollProfile::default()
+    };
+
+    let field_state = if let Some(state) = args.state {
+        let Some(area) = args.area else {
+            println!("--state needs the --area it was seen in!");
+            return;
+        };
+        if args.wave == 0 {
+            println!("Waves are numbered from 1!");
+            return;
+        }
+        if args.wave > 1 && args.seed.is_none() {
+            println!("--state in a later wave needs the --seed at that time!");
+            return;
+        }
+        Some(FieldState {
+            state,
+            area,
+            spawner: args.spawner,
+            wave: args.wave,
+            seed: args.seed,
+        })
+    } else {
+        None
+    };
+
+    let target = if let Some(name) = args.target.as_ref() {
+        if let Some(species) = SPECIES_DICT.get(name.as_str()) {
+            Some(*species)
+        } else {
+            println!("Unknown target species {name}!");
+            return;
+        }
+    } else {
+        None
+    };
+
     if let Ok(client) = SysBotClient::connect(&args.ip, args.port) {
         if let Ok(mo_data) = client.pointer_peek(&[0x42BA6B0, 0x2B0, 0x58, 0x18, 0x20], 0x190) {
             if let Ok(mmo_data) =
@@ -39,9 +174,25 @@ fn main() {
             {
                 let mo_data = &mo_data[..(mo_data.len() - 1)];
                 let mmo_data = &mmo_data[..(mmo_data.len() - 1)];
-                permute_massive_mass_outbreak(mmo_data, Some(satisfy_criteria));
+                if let Some(samples) = args.odds {
+                    print_odds(mmo_data, mo_data, target, samples, &profile);
+                    return;
+                }
+                permute_massive_mass_outbreak(
+                    mmo_data,
+                    field_state,
+                    &profile,
+                    args.export.as_deref(),
+                    Some(satisfy_criteria),
+                );
                 println!("\n==========");
-                permute_block_mass_outbreak(mo_data, Some(satisfy_criteria));
+                permute_block_mass_outbreak(
+                    mo_data,
+                    field_state,
+                    &profile,
+                    args.export.as_deref(),
+                    Some(satisfy_criteria),
+                );
             } else {
                 println!("Failed to get massive outbreak data from console!")
             }
@@ -55,6 +206,9 @@ fn main() {
 
 fn permute_massive_mass_outbreak(
     data: &[u8],
+    state: Option<FieldState>,
+    profile: &ShinyRollProfile,
+    export: Option<&Path>,
     criteria: Option<fn(&EntityResult, &[Advance]) -> bool>,
 ) {
     println!("Permuting Mass Outbreaks.");
@@ -66,8 +220,11 @@ fn permute_massive_mass_outbreak(
         } else {
             AREA_TABLE.get(&0).unwrap()
         };
+        if state.is_some_and(|s| s.spawner.is_none() || s.area != i + 1) {
+            continue;
+        }
         if !area.is_active {
-            println!("No outbreak in {area_name}");
+            println!("No outbreak in {area_name} (area {})", i + 1);
             continue;
         }
 
@@ -78,19 +235,43 @@ fn permute_massive_mass_outbreak(
             if spawner.status() == MassiveOutbreakSpawnerStatus::None {
                 continue;
             }
+            if state.is_some_and(|s| s.spawner != Some(j + 1)) {
+                continue;
+            }
 
             debug_assert!(spawner.has_base());
 
             let seed = spawner.group_seed;
             let spawn: Rc<RefCell<SpawnInfo>> = spawner.into();
 
-            let result = permuter::permute(spawn.clone(), seed, 15, criteria);
+            let result = if let Some(state) = state {
+                match permuter::permute_from_state(
+                    spawn.clone(),
+                    state.wave - 1,
+                    state.get_seed(seed),
+                    state.state,
+                    15,
+                    criteria,
+                    profile.clone(),
+                ) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        println!("Invalid state for spawner {}: {e}", j + 1);
+                        continue;
+                    }
+                }
+            } else {
+                permuter::permute_with_profile(spawn.clone(), seed, 15, criteria, profile.clone())
+            };
             if !result.has_results() {
                 continue;
             }
 
             if !has_printed_area_mmo {
-                println!("Found paths for Massive mass Outbreaks in {area_name}.\n==========");
+                println!(
+                    "Found paths for Massive mass Outbreaks in {area_name} (area {}).\n==========",
+                    i + 1
+                );
                 has_printed_area_mmo = true;
             }
 
@@ -100,18 +281,22 @@ fn permute_massive_mass_outbreak(
                 spawner.x,
                 spawner.y,
                 spawner.z,
-                SPECIES_EN[spawner.display_species as usize]
+                form_util::get_display_name(spawner.display_species, spawner.display_form)
             );
             println!("{}", get_summary(&spawn, "Parameters: "));
             println!("Seed: {}", seed);
             for line in result.get_lines() {
                 println!("{}", line);
             }
+            export_results(&result, export);
             println!();
         }
 
         if !has_printed_area_mmo {
-            println!("Found no results for any Massive Mass Outbreak in {area_name}");
+            println!(
+                "Found no results for any Massive Mass Outbreak in {area_name} (area {})",
+                i + 1
+            );
         } else {
             println!("Done permuting area.\n==========");
         }
@@ -120,6 +305,9 @@ fn permute_massive_mass_outbreak(
 
 fn permute_block_mass_outbreak(
     data: &[u8],
+    state: Option<FieldState>,
+    profile: &ShinyRollProfile,
+    export: Option<&Path>,
     criteria: Option<fn(&EntityResult, &[Advance]) -> bool>,
 ) {
     println!("Permuting mass Outbreaks.");
@@ -132,8 +320,11 @@ fn permute_block_mass_outbreak(
             area_util::AREA_TABLE.get(&0).unwrap()
         };
 
+        if state.is_some_and(|s| s.spawner.is_some() || s.area != i + 1) {
+            continue;
+        }
         if !spawner.has_outbreak() {
-            println!("No outbreak in {area_name}");
+            println!("No outbreak in {area_name} (area {})", i + 1);
             continue;
         }
 
@@ -141,31 +332,98 @@ fn permute_block_mass_outbreak(
 
         let seed = spawner.group_seed;
         let spawn: Rc<RefCell<SpawnInfo>> = spawner.into();
-        let result = permuter::permute(spawn.clone(), seed, 15, criteria);
+        let result = if let Some(state) = state {
+            match permuter::permute_from_state(
+                spawn.clone(),
+                state.wave - 1,
+                state.get_seed(seed),
+                state.state,
+                15,
+                criteria,
+                profile.clone(),
+            ) {
+                Ok(result) => result,
+                Err(e) => {
+                    println!("Invalid state for the outbreak in {area_name}: {e}");
+                    continue;
+                }
+            }
+        } else {
+            permuter::permute_with_profile(spawn.clone(), seed, 15, criteria, profile.clone())
+        };
         if !result.has_results() {
             println!(
-                "Found no paths for {} Mass Outbreak in {area_name}",
-                SPECIES_EN[spawner.display_species as usize]
+                "Found no paths for {} Mass Outbreak in {area_name} (area {})",
+                form_util::get_display_name(spawner.display_species, spawner.display_form),
+                i + 1
             );
             continue;
         }
 
         println!(
-            "Found paths for {} Mass Outbreak in {area_name}:",
-            SPECIES_EN[spawner.display_species as usize]
+            "Found paths for {} Mass Outbreak in {area_name} (area {}):",
+            form_util::get_display_name(spawner.display_species, spawner.display_form),
+            i + 1
         );
         println!("==========");
         println!(
             "Spawner at ({:.1}, {:.1}, {:}) shows {}",
-            spawner.x, spawner.y, spawner.z, SPECIES_EN[spawner.display_species as usize]
+            spawner.x,
+            spawner.y,
+            spawner.z,
+            form_util::get_display_name(spawner.display_species, spawner.display_form)
         );
         println!("{}", get_summary(&spawn, "Parameters: "));
         println!("Seed: {}", seed);
         for line in result.get_lines() {
             println!("{}", line);
         }
+        export_results(&result, export);
         println!();
     }
     println!("Done permuting Mass Outbreaks.");
     println!("==========");
 }
+
+fn print_odds(
+    mmo_data: &[u8],
+    mo_data: &[u8],
+    target: Option<u16>,
+    samples: usize,
+    profile: &ShinyRollProfile,
+) {
+    let seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    let mut rng = Xoroshiro::new(seed);
+    let mmo: MassiveOutbreakSet8a = mmo_data.into();
+    let mo: MassOutbreakSet8a = mo_data.into();
+    let areas =
+        outbreak_odds::get_massive_outbreak_odds(&mmo, target, samples, rng.next_u64(), profile)
+            .into_iter()
+            .chain(outbreak_odds::get_mass_outbreak_odds(
+                &mo,
+                target,
+                samples,
+                rng.next_u64(),
+                profile,
+            ));
+    for area in areas {
+        for line in area.get_lines() {
+            println!("{}", line);
+        }
+        println!("==========");
+    }
+}
+
+fn export_results(result: &PermuteMeta, export: Option<&Path>) {
+    if let Some(dir) = export {
+        for result in result.results.iter() {
+            match export_util::write_pa8(&result.entity, dir) {
+                Ok(path) => println!("Exported {}", path.display()),
+                Err(e) => println!("Failed to export {}: {e}", result.entity.get_name()),
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::permutation::PermuteMeta;
 use crate::util::calculations;
-use crate::{permuter, SpawnState};
+use crate::{permuter, SlotState, SpawnState};
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
 #[repr(u8)]
 pub enum AdvanceType {
     RG,
@@ -27,39 +28,83 @@ pub enum AdvanceType {
     G3,
 }
 
-impl From<usize> for AdvanceType {
-    fn from(num: usize) -> Self {
+impl TryFrom<usize> for AdvanceType {
+    type Error = usize;
+
+    fn try_from(num: usize) -> Result<Self, Self::Error> {
         match num {
-            0 => AdvanceType::RG,
-            1 => AdvanceType::CR,
-            2 => AdvanceType::A1,
-            3 => AdvanceType::A2,
-            4 => AdvanceType::A3,
-            5 => AdvanceType::A4,
-            6 => AdvanceType::B1,
-            7 => AdvanceType::B2,
-            8 => AdvanceType::B3,
-            9 => AdvanceType::B4,
-            10 => AdvanceType::O1,
-            11 => AdvanceType::O2,
-            12 => AdvanceType::O3,
-            13 => AdvanceType::O4,
-            14 => AdvanceType::S2,
-            15 => AdvanceType::S3,
-            16 => AdvanceType::S4,
-            17 => AdvanceType::G1,
-            18 => AdvanceType::G2,
-            _ => AdvanceType::G3,
+            0 => Ok(AdvanceType::RG),
+            1 => Ok(AdvanceType::CR),
+            2 => Ok(AdvanceType::A1),
+            3 => Ok(AdvanceType::A2),
+            4 => Ok(AdvanceType::A3),
+            5 => Ok(AdvanceType::A4),
+            6 => Ok(AdvanceType::B1),
+            7 => Ok(AdvanceType::B2),
+            8 => Ok(AdvanceType::B3),
+            9 => Ok(AdvanceType::B4),
+            10 => Ok(AdvanceType::O1),
+            11 => Ok(AdvanceType::O2),
+            12 => Ok(AdvanceType::O3),
+            13 => Ok(AdvanceType::O4),
+            14 => Ok(AdvanceType::S2),
+            15 => Ok(AdvanceType::S3),
+            16 => Ok(AdvanceType::S4),
+            17 => Ok(AdvanceType::G1),
+            18 => Ok(AdvanceType::G2),
+            19 => Ok(AdvanceType::G3),
+            _ => Err(num),
         }
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdvanceKind {
+    Respawn,
+    ClearRemaining,
+    Knockout,
+    Scare,
+    Ghost,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct Advance {
-    pub advance_type: AdvanceType,
+    pub kind: AdvanceKind,
+    pub aggressive: usize,
+    pub skittish: usize,
+    pub oblivious: usize,
+    pub ghosts: usize,
     pub raw: bool,
 }
 
+impl From<AdvanceType> for Advance {
+    fn from(advance_type: AdvanceType) -> Self {
+        match advance_type {
+            AdvanceType::RG => Advance::respawn(),
+            AdvanceType::CR => Advance::clear_remaining(),
+            AdvanceType::A1 => Advance::knockout(1, 0, 0),
+            AdvanceType::A2 => Advance::knockout(2, 0, 0),
+            AdvanceType::A3 => Advance::knockout(3, 0, 0),
+            AdvanceType::A4 => Advance::knockout(4, 0, 0),
+            AdvanceType::B1 => Advance::knockout(0, 1, 0),
+            AdvanceType::B2 => Advance::knockout(1, 1, 0),
+            AdvanceType::B3 => Advance::knockout(2, 1, 0),
+            AdvanceType::B4 => Advance::knockout(3, 1, 0),
+            AdvanceType::O1 => Advance::knockout(0, 0, 1),
+            AdvanceType::O2 => Advance::knockout(1, 0, 1),
+            AdvanceType::O3 => Advance::knockout(2, 0, 1),
+            AdvanceType::O4 => Advance::knockout(3, 0, 1),
+            AdvanceType::S2 => Advance::scare(2),
+            AdvanceType::S3 => Advance::scare(3),
+            AdvanceType::S4 => Advance::scare(4),
+            AdvanceType::G1 => Advance::ghosts(1),
+            AdvanceType::G2 => Advance::ghosts(2),
+            AdvanceType::G3 => Advance::ghosts(3),
+        }
+    }
+}
+
 impl ToString for AdvanceType {
     fn to_string(&self) -> String {
         match self {
@@ -88,33 +133,150 @@ impl ToString for AdvanceType {
 }
 
 impl Advance {
+    const EMPTY: Advance = Advance {
+        kind: AdvanceKind::Respawn,
+        aggressive: 0,
+        skittish: 0,
+        oblivious: 0,
+        ghosts: 0,
+        raw: true,
+    };
+
+    pub fn respawn() -> Self {
+        Advance::EMPTY
+    }
+
+    pub fn clear_remaining() -> Self {
+        Advance {
+            kind: AdvanceKind::ClearRemaining,
+            ..Advance::EMPTY
+        }
+    }
+
+    pub fn knockout(aggressive: usize, skittish: usize, oblivious: usize) -> Self {
+        Advance {
+            kind: AdvanceKind::Knockout,
+            aggressive,
+            skittish,
+            oblivious,
+            ..Advance::EMPTY
+        }
+    }
+
+    pub fn knockout_aggressive(count: usize) -> Self {
+        Advance::knockout(count, 0, 0)
+    }
+
+    // `count` includes the one skittish or oblivious knockout, so there is no such advance for 0.
+    pub fn knockout_beta(count: usize) -> Option<Self> {
+        Some(Advance::knockout(count.checked_sub(1)?, 1, 0))
+    }
+
+    pub fn knockout_oblivious(count: usize) -> Option<Self> {
+        Some(Advance::knockout(count.checked_sub(1)?, 0, 1))
+    }
+
+    pub fn scare(count: usize) -> Self {
+        Advance {
+            kind: AdvanceKind::Scare,
+            skittish: count,
+            ..Advance::EMPTY
+        }
+    }
+
+    pub fn ghosts(count: usize) -> Self {
+        Advance {
+            kind: AdvanceKind::Ghost,
+            ghosts: count,
+            ..Advance::EMPTY
+        }
+    }
+
     pub fn get_name(&self) -> String {
         if self.raw {
-            self.advance_type.to_string()
+            self.short_code()
         } else {
             self.humanize()
         }
     }
 
+    pub fn legacy_type(&self) -> Option<AdvanceType> {
+        let (a, b, o) = (self.aggressive, self.skittish, self.oblivious);
+        let index = match self.kind {
+            AdvanceKind::Respawn => 0,
+            AdvanceKind::ClearRemaining => 1,
+            AdvanceKind::Knockout if b == 0 && o == 0 && (1..=4).contains(&a) => 1 + a,
+            AdvanceKind::Knockout if b == 1 && o == 0 && a <= 3 => 6 + a,
+            AdvanceKind::Knockout if b == 0 && o == 1 && a <= 3 => 10 + a,
+            AdvanceKind::Scare if (2..=4).contains(&b) => 12 + b,
+            AdvanceKind::Ghost if (1..=3).contains(&self.ghosts) => 16 + self.ghosts,
+            _ => return None,
+        };
+        AdvanceType::try_from(index).ok()
+    }
+
+    pub fn short_code(&self) -> String {
+        if let Some(legacy) = self.legacy_type() {
+            return legacy.to_string();
+        }
+
+        let count = self.advance_count();
+        match self.kind {
+            AdvanceKind::Knockout if self.skittish == 0 && self.oblivious == 0 => {
+                format!("A{count}")
+            }
+            AdvanceKind::Knockout if self.skittish == 1 && self.oblivious == 0 => {
+                format!("B{count}")
+            }
+            AdvanceKind::Knockout if self.skittish == 0 && self.oblivious == 1 => {
+                format!("O{count}")
+            }
+            AdvanceKind::Knockout => {
+                format!("K{}/{}/{}", self.aggressive, self.skittish, self.oblivious)
+            }
+            AdvanceKind::Scare => format!("S{count}"),
+            AdvanceKind::Ghost => format!("G{count}"),
+            AdvanceKind::Respawn => "RG".to_string(),
+            AdvanceKind::ClearRemaining => "CR".to_string(),
+        }
+    }
+
+    pub fn is_same_action(&self, other: &Advance) -> bool {
+        self.kind == other.kind
+            && self.aggressive == other.aggressive
+            && self.skittish == other.skittish
+            && self.oblivious == other.oblivious
+            && self.ghosts == other.ghosts
+    }
+
     pub fn sequence_eq(a: &[Advance], b: &[Advance]) -> bool {
-        a.len() == b.len()
-            && a.iter()
-                .zip(b.iter())
-                .all(|(a, b)| a.advance_type == b.advance_type)
+        a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.is_same_action(b))
     }
 
     pub fn run_forwards(meta: &mut PermuteMeta, advances: &[Advance], seed: u64) -> Vec<SpawnStep> {
+        Advance::replay(meta, advances, seed)
+    }
+
+    pub fn run_forwards_slots(
+        meta: &mut PermuteMeta,
+        advances: &[Advance],
+        seed: u64,
+    ) -> Vec<SpawnStep<SlotState>> {
+        Advance::replay(meta, advances, seed)
+    }
+
+    fn replay<S: ReplayState>(
+        meta: &mut PermuteMeta,
+        advances: &[Advance],
+        seed: u64,
+    ) -> Vec<SpawnStep<S>> {
         let mut steps = Vec::new();
-        let spawner = meta.spawner.clone();
-        let state = spawner.borrow_mut().get_starting_state();
+        let state = S::get_starting_state(meta);
         let table = meta.spawner.borrow().set.table;
-        let (mut seed, mut state) = permuter::update_respawn(meta, table, seed, state);
+        let (mut seed, mut state) = state.update_respawn(meta, table, seed);
         steps.push(SpawnStep {
-            step: Advance {
-                advance_type: AdvanceType::RG,
-                raw: true,
-            },
-            state,
+            step: Advance::respawn(),
+            state: state.clone(),
             seed,
             count_seed: meta.spawner.borrow().count.count_seed,
         });
@@ -131,142 +293,112 @@ impl Advance {
                 state = state.adjust_count(new_alive);
                 steps.push(SpawnStep {
                     step: *advance,
-                    state,
+                    state: state.clone(),
                     seed,
                     count_seed: meta.spawner.borrow().count.count_seed,
-                })
-            } else if advance.advance_type == AdvanceType::CR {
+                });
+                continue;
+            }
+
+            if advance.kind == AdvanceKind::ClearRemaining {
                 let mut next = None;
-                if !meta.spawner.borrow_mut().get_next_wave(&mut next) {
+                if !meta.spawner.borrow().get_next_wave(&mut next) {
                     panic!("No next spawner available!");
                 }
-                if let Some(next) = next {
-                    meta.spawner = next.clone();
-                    state = next.borrow_mut().get_starting_state();
-                    steps.push(SpawnStep {
-                        step: *advance,
-                        state,
-                        seed,
-                        count_seed: meta.spawner.borrow().count.count_seed,
-                    });
-                } else if advance.advance_type as usize >= AdvanceType::G1 as usize {
-                    let count = advance.advance_count();
-                    state = state.add_ghosts(count);
-                    seed = calculations::get_group_seed(seed, state.ghost);
-                    steps.push(SpawnStep {
-                        step: *advance,
-                        state,
-                        seed,
-                        count_seed: meta.spawner.borrow().count.count_seed,
-                    });
-                    continue;
-                } else {
-                    state = advance.advance_state(state);
-                    steps.push(SpawnStep {
-                        step: *advance,
-                        state,
-                        seed,
-                        count_seed: meta.spawner.borrow().count.count_seed,
-                    });
-                }
-
-                if state.count != 0 {
-                    let table = meta.spawner.borrow().set.table;
-                    (seed, state) = permuter::update_respawn(meta, table, seed, state);
-                }
+                meta.spawner = next.unwrap();
+                state = S::get_starting_state(meta);
+            } else if advance.kind == AdvanceKind::Ghost {
+                state = state.add_ghosts(advance.advance_count());
+                seed = calculations::get_group_seed(seed, state.ghost());
                 steps.push(SpawnStep {
-                    step: Advance {
-                        advance_type: AdvanceType::RG,
-                        raw: true,
-                    },
-                    state,
+                    step: *advance,
+                    state: state.clone(),
                     seed,
                     count_seed: meta.spawner.borrow().count.count_seed,
-                })
+                });
+                continue;
+            } else {
+                state = state.advance_state(advance);
             }
+            steps.push(SpawnStep {
+                step: *advance,
+                state: state.clone(),
+                seed,
+                count_seed: meta.spawner.borrow().count.count_seed,
+            });
+
+            if state.count() != 0 {
+                let table = meta.spawner.borrow().set.table;
+                (seed, state) = state.update_respawn(meta, table, seed);
+            }
+            steps.push(SpawnStep {
+                step: Advance::respawn(),
+                state: state.clone(),
+                seed,
+                count_seed: meta.spawner.borrow().count.count_seed,
+            })
         }
         steps
     }
 
     fn humanize(&self) -> String {
-        match self.advance_type {
-            AdvanceType::CR => "Clear Remaining".to_string(),
-            AdvanceType::A1 => "1 Aggressive".to_string(),
-            AdvanceType::A2 => "2 Aggressive".to_string(),
-            AdvanceType::A3 => "3 Aggressive".to_string(),
-            AdvanceType::A4 => "4 Aggressive".to_string(),
-            AdvanceType::B1 => "1 Beta".to_string(),
-            AdvanceType::B2 => "1 Beta + 1 Aggressive".to_string(),
-            AdvanceType::B3 => "1 Beta + 2 Aggressive".to_string(),
-            AdvanceType::B4 => "1 Beta + 3 Aggressive".to_string(),
-            AdvanceType::O1 => "1 Oblivious".to_string(),
-            AdvanceType::O2 => "1 Oblivious + 1 Aggressive".to_string(),
-            AdvanceType::O3 => "1 Oblivious + 2 Aggressive".to_string(),
-            AdvanceType::O4 => "1 Oblivious + 3 Aggressive".to_string(),
-            AdvanceType::S2 => "Multi Scare 2 + Leave".to_string(),
-            AdvanceType::S3 => "Multi Scare 3 + Leave".to_string(),
-            AdvanceType::S4 => "Multi Scare 4 + Leave".to_string(),
-            AdvanceType::G1 => "De-spawn 1 + Leave".to_string(),
-            AdvanceType::G2 => "De-spawn 2 + Leave".to_string(),
-            AdvanceType::G3 => "De-spawn 3 + Leave".to_string(),
-            _ => panic!("Invalid advance type to humanize!"),
+        match self.kind {
+            AdvanceKind::ClearRemaining => "Clear Remaining".to_string(),
+            AdvanceKind::Knockout => {
+                let mut parts = Vec::with_capacity(3);
+                if self.skittish != 0 {
+                    parts.push(format!("{} Beta", self.skittish));
+                }
+                if self.oblivious != 0 {
+                    parts.push(format!("{} Oblivious", self.oblivious));
+                }
+                if self.aggressive != 0 {
+                    parts.push(format!("{} Aggressive", self.aggressive));
+                }
+                parts.join(" + ")
+            }
+            AdvanceKind::Scare => format!("Multi Scare {} + Leave", self.skittish),
+            AdvanceKind::Ghost => format!("De-spawn {} + Leave", self.ghosts),
+            AdvanceKind::Respawn => panic!("Invalid advance type to humanize!"),
         }
     }
 
     pub fn advance_count(&self) -> usize {
-        match self.advance_type {
-            AdvanceType::A1 | AdvanceType::B1 | AdvanceType::O1 | AdvanceType::G1 => 1,
-            AdvanceType::A2
-            | AdvanceType::B2
-            | AdvanceType::O2
-            | AdvanceType::S2
-            | AdvanceType::G2 => 2,
-            AdvanceType::A3
-            | AdvanceType::B3
-            | AdvanceType::O3
-            | AdvanceType::S3
-            | AdvanceType::G3 => 3,
-            AdvanceType::A4 | AdvanceType::B4 | AdvanceType::O4 | AdvanceType::S4 => 4,
-            _ => 0,
-        }
+        self.aggressive + self.skittish + self.oblivious + self.ghosts
     }
 
     pub fn is_multi_aggressive(&self) -> bool {
-        self.advance_type == AdvanceType::A2
-            || self.advance_type == AdvanceType::A3
-            || self.advance_type == AdvanceType::A4
+        self.kind == AdvanceKind::Knockout
+            && self.skittish == 0
+            && self.oblivious == 0
+            && self.aggressive > 1
     }
 
     pub fn is_multi_scare(&self) -> bool {
-        self.advance_type == AdvanceType::S2
-            || self.advance_type == AdvanceType::S3
-            || self.advance_type == AdvanceType::S4
+        self.kind == AdvanceKind::Scare && self.skittish > 1
+    }
+
+    pub fn is_single_beta(&self) -> bool {
+        self.kind == AdvanceKind::Knockout
+            && self.skittish != 0
+            && self.oblivious == 0
+            && self.aggressive == 0
     }
 
     pub fn is_multi_beta(&self) -> bool {
-        self.advance_type == AdvanceType::B2
-            || self.advance_type == AdvanceType::B3
-            || self.advance_type == AdvanceType::B4
+        self.kind == AdvanceKind::Knockout && self.skittish != 0 && self.advance_count() > 1
     }
 
     pub fn is_multi_oblivious(&self) -> bool {
-        self.advance_type == AdvanceType::O2
-            || self.advance_type == AdvanceType::O3
-            || self.advance_type == AdvanceType::O4
+        self.kind == AdvanceKind::Knockout && self.oblivious != 0 && self.advance_count() > 1
     }
 
     pub fn get_removals(&self) -> (usize, usize, usize) {
-        let count = self.advance_count();
-        if self.is_multi_aggressive() || self.advance_type == AdvanceType::A1 {
-            (count, 0, 0)
-        } else if self.is_multi_beta() || self.advance_type == AdvanceType::B1 {
-            (count - 1, 1, 0)
-        } else if self.is_multi_scare() {
-            (0, count, 0)
-        } else if self.is_multi_oblivious() || self.advance_type == AdvanceType::O1 {
-            (count - 1, 0, 1)
-        } else {
-            panic!("Invalid advance type for removals")
+        match self.kind {
+            AdvanceKind::Knockout | AdvanceKind::Scare => {
+                (self.aggressive, self.skittish, self.oblivious)
+            }
+            _ => panic!("Invalid advance type for removals"),
         }
     }
 
@@ -276,21 +408,110 @@ impl Advance {
     }
 }
 
-#[derive(Copy, Clone)]
-pub struct SpawnStep {
+pub trait ReplayState: Clone {
+    fn get_starting_state(meta: &PermuteMeta) -> Self;
+    fn update_respawn(&self, meta: &mut PermuteMeta, table: u64, seed: u64) -> (u64, Self);
+    fn knockout_any(&self, count: usize) -> Self;
+    fn adjust_count(&self, new_alive: usize) -> Self;
+    fn add_ghosts(&self, count: usize) -> Self;
+    fn advance_state(&self, advance: &Advance) -> Self;
+    fn count(&self) -> usize;
+    fn ghost(&self) -> usize;
+    fn get_state(&self) -> String;
+}
+
+impl ReplayState for SpawnState {
+    fn get_starting_state(meta: &PermuteMeta) -> Self {
+        meta.spawner.borrow_mut().get_starting_state()
+    }
+
+    fn update_respawn(&self, meta: &mut PermuteMeta, table: u64, seed: u64) -> (u64, Self) {
+        permuter::update_respawn(meta, table, seed, *self)
+    }
+
+    fn knockout_any(&self, count: usize) -> Self {
+        SpawnState::knockout_any(self, count)
+    }
+
+    fn adjust_count(&self, new_alive: usize) -> Self {
+        SpawnState::adjust_count(self, new_alive)
+    }
+
+    fn add_ghosts(&self, count: usize) -> Self {
+        SpawnState::add_ghosts(self, count)
+    }
+
+    fn advance_state(&self, advance: &Advance) -> Self {
+        advance.advance_state(*self)
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn ghost(&self) -> usize {
+        self.ghost
+    }
+
+    fn get_state(&self) -> String {
+        SpawnState::get_state(self)
+    }
+}
+
+impl ReplayState for SlotState {
+    fn get_starting_state(meta: &PermuteMeta) -> Self {
+        let state = meta.spawner.borrow_mut().get_starting_state();
+        SlotState::get(state.count, state.max_alive)
+    }
+
+    fn update_respawn(&self, meta: &mut PermuteMeta, table: u64, seed: u64) -> (u64, Self) {
+        permuter::update_respawn_slots(meta, table, seed, self)
+    }
+
+    fn knockout_any(&self, count: usize) -> Self {
+        SlotState::knockout_any(self, count)
+    }
+
+    fn adjust_count(&self, new_alive: usize) -> Self {
+        SlotState::adjust_count(self, new_alive)
+    }
+
+    fn add_ghosts(&self, count: usize) -> Self {
+        SlotState::add_ghosts(self, count)
+    }
+
+    fn advance_state(&self, advance: &Advance) -> Self {
+        SlotState::advance_state(self, advance)
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+
+    fn ghost(&self) -> usize {
+        SlotState::ghost(self)
+    }
+
+    fn get_state(&self) -> String {
+        SlotState::get_state(self)
+    }
+}
+
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct SpawnStep<S = SpawnState> {
     pub step: Advance,
-    pub state: SpawnState,
+    pub state: S,
     pub seed: u64,
     pub count_seed: u64,
 }
 
-impl SpawnStep {
+impl<S: ReplayState> SpawnStep<S> {
     pub fn step_summary(&self) -> String {
         format!(
             "{} {} {} {:0>16X} {:0>16X}",
-            self.step.advance_type.to_string(),
+            self.step.short_code(),
             self.state.get_state(),
-            self.state.count,
+            self.state.count(),
             self.seed,
             self.count_seed
         )
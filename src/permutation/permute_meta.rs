@@ -1,4 +1,5 @@
-use crate::generation::EntityResult;
+use crate::generation::encounter_table::EncounterTable;
+use crate::generation::{spawn_generator, EntityResult, ShinyRollProfile};
 use crate::permutation::{Advance, PermuteResult};
 use crate::util::SpawnInfo;
 use std::cell::RefCell;
@@ -15,9 +16,46 @@ pub struct PermuteMeta {
     pub criteria: fn(&EntityResult, &[Advance]) -> bool,
     pub results: Vec<PermuteResult>,
     pub advances: Vec<Advance>,
+    pub profile: ShinyRollProfile,
+    // Tables of every wave, resolved once so spawns don't look them up.
+    encounters: Vec<&'static EncounterTable>,
 }
 
 impl PermuteMeta {
+    pub fn new(
+        spawner: Rc<RefCell<SpawnInfo>>,
+        max_depth: usize,
+        criteria: fn(&EntityResult, &[Advance]) -> bool,
+        profile: ShinyRollProfile,
+    ) -> Self {
+        let mut encounters = Vec::new();
+        let mut next = Some(spawner.clone());
+        while let Some(wave) = next.take() {
+            let table = wave.borrow().set.table;
+            if !encounters.iter().any(|e: &&EncounterTable| e.hash == table) {
+                encounters.push(spawn_generator::get_spawn_table(table));
+            }
+            wave.borrow().get_next_wave(&mut next);
+        }
+        Self {
+            spawner,
+            max_depth,
+            criteria,
+            results: vec![],
+            advances: vec![],
+            profile,
+            encounters,
+        }
+    }
+
+    pub fn get_encounter(&self, table: u64) -> &'static EncounterTable {
+        self.encounters
+            .iter()
+            .find(|e| e.hash == table)
+            .copied()
+            .unwrap_or_else(|| spawn_generator::get_spawn_table(table))
+    }
+
     pub fn has_results(&self) -> bool {
         self.results.len() != 0
     }
@@ -119,7 +157,7 @@ fn is_subset(parent: &[Advance], child: &[Advance]) -> bool {
         false
     } else {
         for (parent, child) in parent.iter().zip(child) {
-            if parent.advance_type != child.advance_type {
+            if !parent.is_same_action(child) {
                 return false;
             }
         }
@@ -1,8 +1,9 @@
 use crate::generation::EntityResult;
-use crate::permutation::{Advance, AdvanceType};
+use crate::permutation::{Advance, AdvanceKind};
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Serialize, Deserialize)]
 pub struct PermuteResult {
     pub advances: Vec<Advance>,
     pub entity: EntityResult,
@@ -18,13 +19,13 @@ impl PermuteResult {
     fn is_bonus(&self) -> bool {
         self.advances
             .iter()
-            .any(|a| a.advance_type == AdvanceType::CR)
+            .any(|a| a.kind == AdvanceKind::ClearRemaining)
     }
 
     fn wave_index(&self) -> usize {
         self.advances
             .iter()
-            .filter(|a| a.advance_type == AdvanceType::CR)
+            .filter(|a| a.kind == AdvanceKind::ClearRemaining)
             .count()
     }
 
@@ -106,7 +107,7 @@ impl PermuteResult {
             return " -- Skittish: Aggressive!";
         }
 
-        if advances.iter().any(|a| a.advance_type == AdvanceType::B1) {
+        if advances.iter().any(Advance::is_single_beta) {
             if !advances.iter().any(Advance::is_multi_aggressive) {
                 return " -- Skittish: Single advances!";
             }
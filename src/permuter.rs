@@ -1,7 +1,7 @@
-use crate::generation::{spawn_generator, EntityResult, SpawnType};
-use crate::permutation::{default_criteria, Advance, AdvanceType, PermuteMeta};
+use crate::generation::{spawn_generator, EntityResult, ShinyRollProfile, SpawnType};
+use crate::permutation::{default_criteria, Advance, PermuteMeta};
 use crate::util::{calculations, SpawnInfo};
-use crate::{SpawnState, Xoroshiro};
+use crate::{SlotState, SpawnState, SpawnStateError, Xoroshiro};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -11,13 +11,28 @@ pub fn permute(
     max_depth: usize,
     criteria: Option<fn(&EntityResult, &[Advance]) -> bool>,
 ) -> PermuteMeta {
-    let mut info = PermuteMeta {
+    permute_with_profile(
         spawner,
+        seed,
         max_depth,
-        criteria: criteria.unwrap_or(default_criteria),
-        results: vec![],
-        advances: vec![],
-    };
+        criteria,
+        ShinyRollProfile::default(),
+    )
+}
+
+pub fn permute_with_profile(
+    spawner: Rc<RefCell<SpawnInfo>>,
+    seed: u64,
+    max_depth: usize,
+    criteria: Option<fn(&EntityResult, &[Advance]) -> bool>,
+    profile: ShinyRollProfile,
+) -> PermuteMeta {
+    let mut info = PermuteMeta::new(
+        spawner,
+        max_depth,
+        criteria.unwrap_or(default_criteria),
+        profile,
+    );
 
     let state = info.spawner.borrow_mut().get_starting_state();
     let table = info.spawner.borrow().set.table;
@@ -27,6 +42,69 @@ pub fn permute(
     info
 }
 
+// Permutes from a state seen on the field. `wave` counts the waves after the first, so 1 is the
+// bonus wave of a massive mass outbreak, and `seed` is the group seed at that point rather than
+// the spawner's original seed. The state must describe every slot of that wave.
+pub fn permute_from_state(
+    spawner: Rc<RefCell<SpawnInfo>>,
+    wave: usize,
+    seed: u64,
+    state: SpawnState,
+    max_depth: usize,
+    criteria: Option<fn(&EntityResult, &[Advance]) -> bool>,
+    profile: ShinyRollProfile,
+) -> Result<PermuteMeta, SpawnStateError> {
+    let spawner = get_wave(spawner, wave).ok_or(SpawnStateError::NoWave(wave))?;
+    validate_state(&spawner.borrow(), &state)?;
+
+    let mut info = PermuteMeta::new(
+        spawner,
+        max_depth,
+        criteria.unwrap_or(default_criteria),
+        profile,
+    );
+
+    let table = info.spawner.borrow().set.table;
+
+    continue_permute(&mut info, table, seed, state);
+
+    Ok(info)
+}
+
+fn get_wave(spawner: Rc<RefCell<SpawnInfo>>, wave: usize) -> Option<Rc<RefCell<SpawnInfo>>> {
+    let mut current = spawner;
+    for _ in 0..wave {
+        let mut next = None;
+        if !current.borrow().get_next_wave(&mut next) {
+            return None;
+        }
+        current = next.unwrap();
+    }
+    Some(current)
+}
+
+fn validate_state(spawner: &SpawnInfo, state: &SpawnState) -> Result<(), SpawnStateError> {
+    state.validate()?;
+    let (min, max) = (spawner.count.min_alive, spawner.count.max_alive);
+    if !(min..=max).contains(&state.max_alive) {
+        return Err(SpawnStateError::SlotCountMismatch {
+            max_alive: state.max_alive,
+            min,
+            max,
+        });
+    }
+    if state.unknown() != 0 {
+        return Err(SpawnStateError::UnknownSlots(state.unknown()));
+    }
+    if !spawner.retain_existing() && state.count > spawner.set.count {
+        return Err(SpawnStateError::CountExceedsWave {
+            count: state.count,
+            wave_count: spawner.set.count,
+        });
+    }
+    Ok(())
+}
+
 fn permute_recursion(meta: &mut PermuteMeta, table: u64, seed: u64, state: SpawnState) {
     if state.count != 0 {
         permute_outbreak(meta, table, seed, state);
@@ -71,6 +149,7 @@ pub fn update_respawn(
         ghosts,
         state.alive_alpha,
         only_one_alpha,
+        None,
     );
     let new_state = state.add(
         respawn,
@@ -82,27 +161,46 @@ pub fn update_respawn(
     (result.seed, new_state)
 }
 
+pub fn update_respawn_slots(
+    meta: &mut PermuteMeta,
+    table: u64,
+    seed: u64,
+    slots: &SlotState,
+) -> (u64, SlotState) {
+    let state = slots.to_spawn_state();
+    if state.count == 0 {
+        return (seed, slots.clone());
+    }
+    let (empty, _, ghosts) = state.get_respawn_info();
+    let only_one_alpha = meta.spawner.borrow().no_multi_alpha();
+    let mut spawned = Vec::with_capacity(empty);
+    let result = generate_spawns(
+        meta,
+        table,
+        seed,
+        empty,
+        ghosts,
+        state.alive_alpha,
+        only_one_alpha,
+        Some(&mut spawned),
+    );
+    (result.seed, slots.respawn(&spawned))
+}
+
 fn continue_permute(meta: &mut PermuteMeta, table: u64, seed: u64, state: SpawnState) {
     let spawner = meta.spawner.clone();
     if spawner.borrow().spawn_type == SpawnType::Regular {
         let count_seed = spawner.borrow().count.count_seed;
         let can_spawn_more = spawner.borrow().count.can_spawn_more(state.alive());
         if can_spawn_more {
-            meta.start(Advance {
-                advance_type: AdvanceType::RG,
-                raw: true,
-            });
+            meta.start(Advance::respawn());
             permute_recursion(meta, table, seed, state);
             meta.end();
             spawner.borrow_mut().count.count_seed = count_seed;
         }
 
         for i in 1..=state.alive() {
-            let step = AdvanceType::A1 as usize + (i - 1);
-            meta.start(Advance {
-                advance_type: AdvanceType::from(step),
-                raw: true,
-            });
+            meta.start(Advance::knockout_aggressive(i));
             let new_state = state.knockout_any(i);
             permute_recursion(meta, table, seed, new_state);
             meta.end();
@@ -119,11 +217,7 @@ fn continue_permute(meta: &mut PermuteMeta, table: u64, seed: u64, state: SpawnS
 
     if state.alive_aggressive != 0 {
         for i in 1..=state.alive_aggressive {
-            let step = AdvanceType::A1 as usize + (i - 1);
-            meta.start(Advance {
-                advance_type: AdvanceType::from(step),
-                raw: true,
-            });
+            meta.start(Advance::knockout_aggressive(i));
             let new_state = state.knockout_aggressive(i);
             permute_recursion(meta, table, seed, new_state);
             meta.end();
@@ -132,11 +226,7 @@ fn continue_permute(meta: &mut PermuteMeta, table: u64, seed: u64, state: SpawnS
 
     if state.alive_oblivious != 0 {
         for i in 0..=state.alive_aggressive {
-            let step = AdvanceType::O1 as usize + i;
-            meta.start(Advance {
-                advance_type: AdvanceType::from(step),
-                raw: true,
-            });
+            meta.start(Advance::knockout(i, 0, 1));
             let new_state = state.knockout_oblivious(i + 1);
             permute_recursion(meta, table, seed, new_state);
             meta.end();
@@ -145,11 +235,7 @@ fn continue_permute(meta: &mut PermuteMeta, table: u64, seed: u64, state: SpawnS
 
     if state.alive_beta != 0 {
         for i in 0..=state.alive_aggressive {
-            let step = AdvanceType::B1 as usize + i;
-            meta.start(Advance {
-                advance_type: AdvanceType::from(step),
-                raw: true,
-            });
+            meta.start(Advance::knockout(i, 1, 0));
             let new_state = state.knockout_beta(i + 1);
             permute_recursion(meta, table, seed, new_state);
             meta.end();
@@ -157,17 +243,14 @@ fn continue_permute(meta: &mut PermuteMeta, table: u64, seed: u64, state: SpawnS
     }
 
     for i in 2..state.alive_beta {
-        let step = AdvanceType::S2 as usize + (i - 2);
-        meta.start(Advance {
-            advance_type: AdvanceType::from(step),
-            raw: true,
-        });
+        meta.start(Advance::scare(i));
         let new_state = state.scare(i);
         permute_recursion(meta, table, seed, new_state);
         meta.end();
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_spawns(
     meta: &mut PermuteMeta,
     table: u64,
@@ -176,11 +259,13 @@ fn generate_spawns(
     ghosts: usize,
     current_alpha: usize,
     only_one_alpha: bool,
+    mut spawned: Option<&mut Vec<EntityResult>>,
 ) -> GenerationResult {
     let mut alpha = 0;
     let mut aggressive = 0;
     let mut beta = 0;
     let mut oblivious = 0;
+    let encounter = meta.get_encounter(table);
     let mut rng = Xoroshiro::new(seed);
     for i in 1..=count {
         let sub_seed = rng.next_u64();
@@ -192,9 +277,16 @@ fn generate_spawns(
 
         let no_alpha = only_one_alpha && (current_alpha + alpha) != 0;
         let spawn_type = meta.spawner.borrow().spawn_type;
-        if let Some(generate) =
-            spawn_generator::generate(seed, i, sub_seed, alpha_seed, table, spawn_type, no_alpha)
-        {
+        if let Some(generate) = spawn_generator::generate_from_table(
+            seed,
+            i,
+            sub_seed,
+            alpha_seed,
+            encounter,
+            spawn_type,
+            &meta.profile,
+            no_alpha,
+        ) {
             if generate.is_alpha {
                 alpha += 1;
             } else if generate.is_oblivious() {
@@ -204,7 +296,11 @@ fn generate_spawns(
             } else {
                 aggressive += 1;
             }
-            if meta.is_result(&generate) {
+            let is_result = meta.is_result(&generate);
+            if let Some(spawned) = spawned.as_deref_mut() {
+                spawned.push(generate.clone());
+            }
+            if is_result {
                 meta.add_result(generate);
             }
         }
@@ -226,10 +322,7 @@ fn permute_next_table(
     exist: SpawnState,
 ) {
     if !next.borrow().retain_existing() {
-        meta.start(Advance {
-            advance_type: AdvanceType::CR,
-            raw: true,
-        });
+        meta.start(Advance::clear_remaining());
     }
 
     let current = meta.spawner.clone();
@@ -254,11 +347,7 @@ fn permute_next_table(
 fn permute_add_ghosts(meta: &mut PermuteMeta, seed: u64, table: u64, state: SpawnState) {
     let remain = state.empty_ghost_slots();
     for i in 1..=remain {
-        let step = AdvanceType::G1 as usize + (i - 1);
-        meta.start(Advance {
-            advance_type: AdvanceType::from(step),
-            raw: true,
-        });
+        meta.start(Advance::ghosts(i));
         let new_state = state.add_ghosts(i);
         let g_seed = calculations::get_group_seed(seed, new_state.ghost);
         permute_recursion(meta, table, g_seed, new_state);
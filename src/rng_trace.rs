@@ -0,0 +1,108 @@
+use crate::Xoroshiro;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RngDraw {
+    pub label: Cow<'static, str>,
+    // Separates repeated draws with the same label, e.g. each PID roll or IV.
+    pub index: usize,
+    // State before the draw.
+    pub seed0: u64,
+    pub seed1: u64,
+    // Zero for unbounded draws.
+    pub max: u64,
+    pub value: u64,
+    // Draws thrown away by next_max before an in-range value was found.
+    pub rerolls: usize,
+}
+
+impl Display for RngDraw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<12}{:>2} [{:016X} {:016X}] ",
+            self.label, self.index, self.seed0, self.seed1
+        )?;
+        if self.max == 0 {
+            write!(f, "{:016X}", self.value)
+        } else {
+            write!(f, "{} / {}", self.value, self.max)?;
+            if self.rerolls != 0 {
+                write!(f, " ({} rerolls)", self.rerolls)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// Records every draw when tracing, otherwise behaves exactly like the wrapped Xoroshiro.
+pub struct TracedRng {
+    pub rng: Xoroshiro,
+    pub draws: Option<Vec<RngDraw>>,
+}
+
+impl TracedRng {
+    pub fn new(seed: u64, trace: bool) -> Self {
+        Self {
+            rng: Xoroshiro::new(seed),
+            draws: if trace { Some(Vec::new()) } else { None },
+        }
+    }
+
+    pub fn next_u64(&mut self, label: &'static str, index: usize) -> u64 {
+        if self.draws.is_none() {
+            return self.rng.next_u64();
+        }
+        let before = self.rng;
+        let value = self.rng.next_u64();
+        self.record(label, index, before, 0, value, 0);
+        value
+    }
+
+    pub fn next_max(&mut self, label: &'static str, index: usize, max: u64) -> u64 {
+        if self.draws.is_none() {
+            return self.rng.next_max(max);
+        }
+        let before = self.rng;
+        let (value, rerolls) = self.rng.next_max_rerolls(max);
+        self.record(label, index, before, max, value, rerolls);
+        value
+    }
+
+    pub fn next_f32(&mut self, label: &'static str, range: f32, bias: f32) -> f32 {
+        if self.draws.is_none() {
+            return self.rng.next_f32(range, bias);
+        }
+        // Records the raw draw the float was made from, which is more useful than the float itself.
+        let before = self.rng;
+        let mut raw_rng = before;
+        let raw = raw_rng.next_u64();
+        let value = self.rng.next_f32(range, bias);
+        self.record(label, 0, before, 0, raw, 0);
+        value
+    }
+
+    fn record(
+        &mut self,
+        label: &'static str,
+        index: usize,
+        before: Xoroshiro,
+        max: u64,
+        value: u64,
+        rerolls: usize,
+    ) {
+        if let Some(draws) = self.draws.as_mut() {
+            draws.push(RngDraw {
+                label: Cow::Borrowed(label),
+                index,
+                seed0: before.seed0,
+                seed1: before.seed1,
+                max,
+                value,
+                rerolls,
+            });
+        }
+    }
+}
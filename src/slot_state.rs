@@ -0,0 +1,281 @@
+use crate::generation::EntityResult;
+use crate::permutation::{Advance, AdvanceKind};
+use crate::SpawnState;
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum SlotBehavior {
+    Aggressive,
+    Skittish,
+    Oblivious,
+}
+
+impl From<&EntityResult> for SlotBehavior {
+    fn from(entity: &EntityResult) -> Self {
+        if entity.is_aggressive() {
+            SlotBehavior::Aggressive
+        } else if entity.is_oblivious() {
+            SlotBehavior::Oblivious
+        } else {
+            SlotBehavior::Skittish
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SlotOccupant {
+    pub entity: EntityResult,
+    pub behavior: SlotBehavior,
+}
+
+impl SlotOccupant {
+    pub fn is_alpha(&self) -> bool {
+        self.entity.is_alpha
+    }
+
+    pub fn is_same_entity(&self, other: &SlotOccupant) -> bool {
+        self.entity.group_seed == other.entity.group_seed && self.entity.index == other.entity.index
+    }
+}
+
+impl From<EntityResult> for SlotOccupant {
+    fn from(entity: EntityResult) -> Self {
+        let behavior = SlotBehavior::from(&entity);
+        Self { entity, behavior }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub enum SpawnSlot {
+    #[default]
+    Empty,
+    Ghost,
+    Unknown,
+    Occupied(SlotOccupant),
+}
+
+impl SpawnSlot {
+    pub fn is_dead(&self) -> bool {
+        matches!(self, SpawnSlot::Empty | SpawnSlot::Ghost)
+    }
+
+    pub fn occupant(&self) -> Option<&SlotOccupant> {
+        if let SpawnSlot::Occupied(occupant) = self {
+            Some(occupant)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_glyph(&self) -> char {
+        match self {
+            SpawnSlot::Empty => 'X',
+            SpawnSlot::Ghost => '~',
+            SpawnSlot::Unknown => '?',
+            SpawnSlot::Occupied(occupant) if occupant.is_alpha() => 'a',
+            SpawnSlot::Occupied(occupant) => match occupant.behavior {
+                SlotBehavior::Aggressive => 'A',
+                SlotBehavior::Skittish => 'B',
+                SlotBehavior::Oblivious => 'O',
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SlotState {
+    pub count: usize,
+    pub slots: Vec<SpawnSlot>,
+}
+
+impl SlotState {
+    pub fn get(total_count: usize, alive_count: usize) -> Self {
+        Self {
+            count: total_count,
+            slots: vec![SpawnSlot::Empty; alive_count],
+        }
+    }
+
+    pub fn max_alive(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn dead(&self) -> usize {
+        self.slots.iter().filter(|s| s.is_dead()).count()
+    }
+
+    pub fn alive(&self) -> usize {
+        self.max_alive() - self.dead()
+    }
+
+    pub fn ghost(&self) -> usize {
+        self.slots
+            .iter()
+            .filter(|s| matches!(s, SpawnSlot::Ghost))
+            .count()
+    }
+
+    pub fn occupants(&self) -> impl Iterator<Item = &SlotOccupant> {
+        self.slots.iter().filter_map(SpawnSlot::occupant)
+    }
+
+    pub fn respawn(&self, spawned: &[EntityResult]) -> Self {
+        let (empty_slots, respawn, ghosts) = self.to_spawn_state().get_respawn_info();
+        debug_assert!(spawned.len() <= respawn);
+        debug_assert_eq!(empty_slots, self.dead());
+
+        let mut slots = self.slots.clone();
+        let mut spawned = spawned.iter();
+        for (i, slot) in slots.iter_mut().filter(|s| s.is_dead()).enumerate() {
+            *slot = if i < ghosts {
+                SpawnSlot::Ghost
+            } else if let Some(entity) = spawned.next() {
+                SpawnSlot::Occupied(entity.clone().into())
+            } else {
+                SpawnSlot::Empty
+            };
+        }
+
+        Self {
+            count: self.count - respawn,
+            slots,
+        }
+    }
+
+    pub fn get_removed(&self, aggro: usize, beta: usize, oblivious: usize) -> Vec<usize> {
+        let mut removed = Vec::with_capacity(aggro + beta + oblivious);
+        self.take_slots(&mut removed, aggro, |o| {
+            o.behavior == SlotBehavior::Aggressive && o.is_alpha()
+        });
+        let alphas = removed.len();
+        self.take_slots(&mut removed, aggro - alphas, |o| {
+            o.behavior == SlotBehavior::Aggressive
+        });
+        self.take_slots(&mut removed, beta, |o| o.behavior == SlotBehavior::Skittish);
+        self.take_slots(&mut removed, oblivious, |o| {
+            o.behavior == SlotBehavior::Oblivious
+        });
+        debug_assert_eq!(removed.len(), aggro + beta + oblivious);
+        removed
+    }
+
+    fn take_slots(
+        &self,
+        removed: &mut Vec<usize>,
+        count: usize,
+        filter: impl Fn(&SlotOccupant) -> bool,
+    ) {
+        let positions = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| !removed.contains(i) && s.occupant().is_some_and(&filter))
+            .map(|(i, _)| i)
+            .take(count)
+            .collect::<Vec<_>>();
+        removed.extend(positions);
+    }
+
+    pub fn get_removed_by(&self, advance: &Advance) -> Vec<usize> {
+        match advance.kind {
+            AdvanceKind::Knockout | AdvanceKind::Scare => {
+                let (aggro, beta, oblivious) = advance.get_removals();
+                self.get_removed(aggro, beta, oblivious)
+            }
+            _ => vec![],
+        }
+    }
+
+    pub fn remove_slots(&self, positions: &[usize]) -> Self {
+        let mut slots = self.slots.clone();
+        for position in positions {
+            debug_assert!(slots[*position].occupant().is_some());
+            slots[*position] = SpawnSlot::Empty;
+        }
+        Self {
+            count: self.count,
+            slots,
+        }
+    }
+
+    pub fn remove(&self, aggro: usize, beta: usize, oblivious: usize) -> Self {
+        self.remove_slots(&self.get_removed(aggro, beta, oblivious))
+    }
+
+    pub fn knockout_any(&self, count: usize) -> Self {
+        let (aggro, beta, oblivious) = self.occupants().fold((0, 0, 0), |c, o| match o.behavior {
+            SlotBehavior::Aggressive => (c.0 + 1, c.1, c.2),
+            SlotBehavior::Skittish => (c.0, c.1 + 1, c.2),
+            SlotBehavior::Oblivious => (c.0, c.1, c.2 + 1),
+        });
+        let aggro = aggro.min(count);
+        let beta = beta.min(count - aggro);
+        let oblivious = oblivious.min(count - aggro - beta);
+        self.remove(aggro, beta, oblivious)
+    }
+
+    pub fn advance_state(&self, advance: &Advance) -> Self {
+        self.remove_slots(&self.get_removed_by(advance))
+    }
+
+    pub fn add_ghosts(&self, count: usize) -> Self {
+        let mut added = 0;
+        let slots = self
+            .slots
+            .iter()
+            .map(|slot| match slot {
+                SpawnSlot::Empty if added < count => {
+                    added += 1;
+                    SpawnSlot::Ghost
+                }
+                SpawnSlot::Occupied(_) => SpawnSlot::Unknown,
+                _ => slot.clone(),
+            })
+            .collect();
+        debug_assert_eq!(added, count);
+        Self {
+            count: self.count,
+            slots,
+        }
+    }
+
+    pub fn adjust_count(&self, new_alive: usize) -> Self {
+        let max_alive = new_alive.max(self.alive());
+        let mut slots = self
+            .slots
+            .iter()
+            .filter(|s| !s.is_dead())
+            .cloned()
+            .collect::<Vec<_>>();
+        slots.resize(max_alive, SpawnSlot::Empty);
+        Self {
+            count: max_alive - self.alive(),
+            slots,
+        }
+    }
+
+    pub fn to_spawn_state(&self) -> SpawnState {
+        let mut state = SpawnState {
+            count: self.count,
+            max_alive: self.max_alive(),
+            ghost: self.ghost(),
+            dead: self.dead(),
+            ..SpawnState::default()
+        };
+        for occupant in self.occupants() {
+            match occupant.behavior {
+                SlotBehavior::Aggressive => state.alive_aggressive += 1,
+                SlotBehavior::Skittish => state.alive_beta += 1,
+                SlotBehavior::Oblivious => state.alive_oblivious += 1,
+            }
+            if occupant.is_alpha() {
+                state.alive_alpha += 1;
+            }
+        }
+        state
+    }
+
+    pub fn get_state(&self) -> String {
+        self.slots.iter().map(SpawnSlot::get_glyph).collect()
+    }
+}
@@ -1,6 +1,9 @@
-use std::fmt::Debug;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
 
-#[derive(Default, Copy, Clone, Debug)]
+#[derive(Default, Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct SpawnState {
     pub count: usize,
     pub max_alive: usize,
@@ -17,6 +20,14 @@ impl SpawnState {
         self.max_alive - self.dead
     }
 
+    // Slots written as '?', which count as alive but are not known to be aggressive, skittish or
+    // oblivious, so they can't be knocked out.
+    pub fn unknown(&self) -> usize {
+        self.max_alive.saturating_sub(
+            self.alive_aggressive + self.alive_beta + self.alive_oblivious + self.dead,
+        )
+    }
+
     pub fn max_ghosts(&self) -> usize {
         self.max_alive - 1
     }
@@ -59,9 +70,9 @@ impl SpawnState {
     }
 
     pub fn knockout_any(&self, count: usize) -> Self {
-        let aggro = 0.max(self.alive_aggressive.min(count));
-        let beta = 0.max((self.alive_beta.saturating_sub(aggro)).min(count));
-        let obli = 0.max((self.alive_beta.saturating_sub(aggro).saturating_sub(beta)).min(count));
+        let aggro = self.alive_aggressive.min(count);
+        let beta = self.alive_beta.min(count - aggro);
+        let obli = self.alive_oblivious.min(count - aggro - beta);
         self.remove(aggro, beta, obli)
     }
 
@@ -205,4 +216,162 @@ impl SpawnState {
 
         result.into_iter().collect()
     }
+
+    pub fn validate(&self) -> Result<(), SpawnStateError> {
+        if self.max_alive == 0 {
+            return Err(SpawnStateError::NoSlots);
+        }
+        if self.alive_alpha > self.alive_aggressive {
+            return Err(SpawnStateError::AlphaNotAggressive {
+                alpha: self.alive_alpha,
+                aggressive: self.alive_aggressive,
+            });
+        }
+        if self.ghost > self.dead {
+            return Err(SpawnStateError::GhostsExceedDead {
+                ghost: self.ghost,
+                dead: self.dead,
+            });
+        }
+        if self.ghost > self.max_ghosts() {
+            return Err(SpawnStateError::TooManyGhosts {
+                ghost: self.ghost,
+                max_ghosts: self.max_ghosts(),
+            });
+        }
+        let used = self.alive_aggressive + self.alive_beta + self.alive_oblivious + self.dead;
+        if used > self.max_alive {
+            return Err(SpawnStateError::SlotOverflow {
+                used,
+                max_alive: self.max_alive,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SpawnState {
+    type Err = SpawnStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let glyphs = parts.next().ok_or(SpawnStateError::NoSlots)?;
+        let count = parts.next().ok_or(SpawnStateError::MissingCount)?;
+        let count =
+            usize::from_str(count).map_err(|_| SpawnStateError::InvalidCount(count.to_string()))?;
+        if let Some(extra) = parts.next() {
+            return Err(SpawnStateError::UnexpectedInput(extra.to_string()));
+        }
+
+        let mut state = SpawnState {
+            count,
+            max_alive: glyphs.chars().count(),
+            ..SpawnState::default()
+        };
+        for glyph in glyphs.chars() {
+            match glyph {
+                'a' => {
+                    state.alive_alpha += 1;
+                    state.alive_aggressive += 1;
+                }
+                'A' => state.alive_aggressive += 1,
+                'B' => state.alive_beta += 1,
+                'O' => state.alive_oblivious += 1,
+                '~' => {
+                    state.ghost += 1;
+                    state.dead += 1;
+                }
+                'X' => state.dead += 1,
+                '?' => {}
+                _ => return Err(SpawnStateError::InvalidGlyph(glyph)),
+            }
+        }
+
+        state.validate()?;
+        Ok(state)
+    }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SpawnStateError {
+    NoSlots,
+    MissingCount,
+    InvalidCount(String),
+    UnexpectedInput(String),
+    InvalidGlyph(char),
+    AlphaNotAggressive {
+        alpha: usize,
+        aggressive: usize,
+    },
+    GhostsExceedDead {
+        ghost: usize,
+        dead: usize,
+    },
+    TooManyGhosts {
+        ghost: usize,
+        max_ghosts: usize,
+    },
+    SlotOverflow {
+        used: usize,
+        max_alive: usize,
+    },
+    UnknownSlots(usize),
+    SlotCountMismatch {
+        max_alive: usize,
+        min: usize,
+        max: usize,
+    },
+    CountExceedsWave {
+        count: usize,
+        wave_count: usize,
+    },
+    NoWave(usize),
+}
+
+impl Display for SpawnStateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpawnStateError::NoSlots => write!(f, "state must contain at least one slot"),
+            SpawnStateError::MissingCount => write!(f, "missing remaining spawn count"),
+            SpawnStateError::InvalidCount(count) => write!(f, "invalid spawn count: {count}"),
+            SpawnStateError::UnexpectedInput(input) => write!(f, "unexpected input: {input}"),
+            SpawnStateError::InvalidGlyph(glyph) => {
+                write!(f, "invalid slot glyph '{glyph}', expected one of aABO~X?")
+            }
+            SpawnStateError::AlphaNotAggressive { alpha, aggressive } => write!(
+                f,
+                "{alpha} alive alphas but only {aggressive} alive aggressive"
+            ),
+            SpawnStateError::GhostsExceedDead { ghost, dead } => {
+                write!(f, "{ghost} ghosts but only {dead} dead slots")
+            }
+            SpawnStateError::TooManyGhosts { ghost, max_ghosts } => {
+                write!(f, "{ghost} ghosts but at most {max_ghosts} are allowed")
+            }
+            SpawnStateError::SlotOverflow { used, max_alive } => {
+                write!(f, "{used} slots used but only {max_alive} available")
+            }
+            SpawnStateError::UnknownSlots(count) => {
+                write!(
+                    f,
+                    "{count} unknown slots, every slot must be known to permute"
+                )
+            }
+            SpawnStateError::SlotCountMismatch {
+                max_alive,
+                min,
+                max,
+            } => {
+                write!(f, "{max_alive} slots but the spawner has {min} to {max}")
+            }
+            SpawnStateError::CountExceedsWave { count, wave_count } => {
+                write!(f, "{count} remaining but the wave only has {wave_count}")
+            }
+            SpawnStateError::NoWave(wave) => {
+                write!(f, "spawner has no wave {wave} after the first")
+            }
+        }
+    }
+}
+
+impl Error for SpawnStateError {}
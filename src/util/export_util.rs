@@ -0,0 +1,125 @@
+use crate::generation::EntityResult;
+use crate::util::form_util;
+use pkhex_rs::game_strings::{MOVES_EN, NATURES_EN};
+use pkhex_rs::PA8;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+// Results with an unknown level (0) can't be exported. The TID and SID come from `fake_tid`, so
+// the file is shiny exactly when the result is.
+pub fn get_pa8(entity: &EntityResult) -> Result<PA8, ExportError> {
+    if entity.level == 0 {
+        return Err(ExportError::UnknownLevel);
+    }
+
+    let mut pkm = PA8::new();
+    pkm.set_species(entity.species);
+    pkm.set_form(entity.form);
+    pkm.set_encryption_constant(entity.ec);
+    pkm.set_pid(entity.pid);
+    pkm.set_tid16(entity.fake_tid as u16);
+    pkm.set_sid16((entity.fake_tid >> 16) as u16);
+    pkm.set_ivs(&entity.ivs);
+    pkm.set_nature(entity.nature);
+    pkm.set_stat_nature(entity.nature);
+    pkm.set_gender(entity.gender);
+    pkm.set_ability(entity.get_ability_id());
+    pkm.set_ability_number(1 << entity.ability);
+    pkm.set_height_scalar(entity.height);
+    pkm.set_weight_scalar(entity.weight);
+    pkm.reset_height();
+    pkm.reset_weight();
+    pkm.set_is_alpha(entity.is_alpha);
+    pkm.set_current_level(entity.level as u8);
+    pkm.set_met_level(entity.level as u8);
+    pkm.set_moves(&entity.moves);
+    pkm.set_alpha_move(entity.alpha_move);
+    pkm.reset_party_stats();
+    pkm.refresh_checksum();
+    Ok(pkm)
+}
+
+// The decrypted party data of `get_pa8`.
+pub fn get_pa8_data(entity: &EntityResult) -> Result<Vec<u8>, ExportError> {
+    Ok(get_pa8(entity)?.get_decrypted_party_data())
+}
+
+pub fn get_pa8_file_name(entity: &EntityResult) -> String {
+    let shiny = if entity.is_shiny { " ★" } else { "" };
+    format!(
+        "{:04}{shiny} - {} - {:08X}{:08X}.pa8",
+        entity.species,
+        entity.get_name(),
+        entity.ec,
+        entity.pid
+    )
+}
+
+// Writes `get_pa8_data` to `dir`, named by `get_pa8_file_name`.
+pub fn write_pa8(entity: &EntityResult, dir: impl AsRef<Path>) -> Result<PathBuf, ExportError> {
+    let data = get_pa8_data(entity)?;
+    let path = dir.as_ref().join(get_pa8_file_name(entity));
+    std::fs::write(&path, data).map_err(|e| ExportError::Io {
+        path: path.display().to_string(),
+        reason: e.to_string(),
+    })?;
+    Ok(path)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExportError {
+    UnknownLevel,
+    Io { path: String, reason: String },
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::UnknownLevel => write!(f, "level is unknown"),
+            ExportError::Io { path, reason } => write!(f, "failed to write {path}: {reason}"),
+        }
+    }
+}
+
+impl Error for ExportError {}
+
+const STAT_NAMES: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+// Only IVs below 31 are listed, matching what Showdown and PKHeX write themselves.
+pub fn get_showdown_text(entity: &EntityResult) -> String {
+    let mut lines = Vec::with_capacity(11);
+    let name = form_util::get_showdown_name(entity.species, entity.form);
+    lines.push(match entity.gender {
+        0 => format!("{name} (M)"),
+        1 => format!("{name} (F)"),
+        _ => name,
+    });
+    lines.push(format!("Ability: {}", entity.get_ability_name()));
+    if entity.level != 0 {
+        lines.push(format!("Level: {}", entity.level));
+    }
+    if entity.is_shiny {
+        lines.push(String::from("Shiny: Yes"));
+    }
+    if entity.is_alpha {
+        lines.push(String::from("Alpha: Yes"));
+    }
+    lines.push(format!("{} Nature", NATURES_EN[entity.nature as usize]));
+
+    let ivs = entity
+        .ivs
+        .iter()
+        .zip(STAT_NAMES)
+        .filter(|(iv, _)| **iv != 31)
+        .map(|(iv, stat)| format!("{iv} {stat}"))
+        .collect::<Vec<String>>();
+    if !ivs.is_empty() {
+        lines.push(format!("IVs: {}", ivs.join(" / ")));
+    }
+
+    for m in entity.moves.iter().filter(|m| **m != 0) {
+        lines.push(format!("- {}", MOVES_EN[*m as usize]));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,118 @@
+use pkhex_rs::game_strings::SPECIES_EN;
+use pkhex_rs::Species;
+
+const UNOWN_FORMS: [&str; 28] = [
+    "A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S",
+    "T", "U", "V", "W", "X", "Y", "Z", "!", "?",
+];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FormName {
+    // Written before the species name, e.g. "Hisuian Growlithe".
+    Prefix(&'static str),
+    // Written after the species name, e.g. "Kleavor (Lord)".
+    Suffix(&'static str),
+}
+
+const FORM_NAMES: [(Species, u16, FormName); 43] = [
+    (Species::Vulpix, 1, FormName::Prefix("Alolan")),
+    (Species::Ninetales, 1, FormName::Prefix("Alolan")),
+    (Species::Growlithe, 1, FormName::Prefix("Hisuian")),
+    (Species::Arcanine, 1, FormName::Prefix("Hisuian")),
+    (Species::Arcanine, 2, FormName::Suffix("Lord")),
+    (Species::Voltorb, 1, FormName::Prefix("Hisuian")),
+    (Species::Electrode, 1, FormName::Prefix("Hisuian")),
+    (Species::Electrode, 2, FormName::Suffix("Lord")),
+    (Species::Typhlosion, 1, FormName::Prefix("Hisuian")),
+    (Species::Qwilfish, 1, FormName::Prefix("Hisuian")),
+    (Species::Sneasel, 1, FormName::Prefix("Hisuian")),
+    (Species::Burmy, 1, FormName::Suffix("Sandy Cloak")),
+    (Species::Burmy, 2, FormName::Suffix("Trash Cloak")),
+    (Species::Wormadam, 1, FormName::Suffix("Sandy Cloak")),
+    (Species::Wormadam, 2, FormName::Suffix("Trash Cloak")),
+    (Species::Mothim, 1, FormName::Suffix("Sandy Cloak")),
+    (Species::Mothim, 2, FormName::Suffix("Trash Cloak")),
+    (Species::Cherrim, 1, FormName::Suffix("Sunshine")),
+    (Species::Shellos, 1, FormName::Suffix("East Sea")),
+    (Species::Gastrodon, 1, FormName::Suffix("East Sea")),
+    (Species::Rotom, 1, FormName::Prefix("Heat")),
+    (Species::Rotom, 2, FormName::Prefix("Wash")),
+    (Species::Rotom, 3, FormName::Prefix("Frost")),
+    (Species::Rotom, 4, FormName::Prefix("Fan")),
+    (Species::Rotom, 5, FormName::Prefix("Mow")),
+    (Species::Dialga, 1, FormName::Suffix("Origin")),
+    (Species::Palkia, 1, FormName::Suffix("Origin")),
+    (Species::Giratina, 1, FormName::Suffix("Origin")),
+    (Species::Shaymin, 1, FormName::Suffix("Sky")),
+    (Species::Samurott, 1, FormName::Prefix("Hisuian")),
+    (Species::Lilligant, 1, FormName::Prefix("Hisuian")),
+    (Species::Lilligant, 2, FormName::Suffix("Lady")),
+    (Species::Basculin, 1, FormName::Prefix("Blue-Striped")),
+    (Species::Basculin, 2, FormName::Prefix("White-Striped")),
+    (Species::Zorua, 1, FormName::Prefix("Hisuian")),
+    (Species::Zoroark, 1, FormName::Prefix("Hisuian")),
+    (Species::Braviary, 1, FormName::Prefix("Hisuian")),
+    (Species::Sliggoo, 1, FormName::Prefix("Hisuian")),
+    (Species::Goodra, 1, FormName::Prefix("Hisuian")),
+    (Species::Avalugg, 1, FormName::Prefix("Hisuian")),
+    (Species::Avalugg, 2, FormName::Suffix("Lord")),
+    (Species::Decidueye, 1, FormName::Prefix("Hisuian")),
+    (Species::Kleavor, 1, FormName::Suffix("Lord")),
+];
+
+const THERIAN_SPECIES: [Species; 4] = [
+    Species::Tornadus,
+    Species::Thundurus,
+    Species::Landorus,
+    Species::Enamorus,
+];
+
+pub fn get_form_name(species: u16, form: u16) -> Option<FormName> {
+    if form == 0 {
+        return None;
+    }
+
+    if species == Species::Unown as u16 {
+        return UNOWN_FORMS.get(form as usize).map(|f| FormName::Suffix(f));
+    }
+    if species == Species::Basculegion as u16 && form == 1 {
+        return Some(FormName::Suffix("Female"));
+    }
+    if form == 1 && THERIAN_SPECIES.iter().any(|s| *s as u16 == species) {
+        return Some(FormName::Suffix("Therian"));
+    }
+
+    FORM_NAMES
+        .iter()
+        .find(|(s, f, _)| *s as u16 == species && *f == form)
+        .map(|(_, _, name)| *name)
+}
+
+pub fn get_display_name(species: u16, form: u16) -> String {
+    let name = SPECIES_EN[species as usize];
+    match get_form_name(species, form) {
+        Some(FormName::Prefix(prefix)) => format!("{prefix} {name}"),
+        Some(FormName::Suffix(suffix)) => format!("{name} ({suffix})"),
+        None if form != 0 => format!("{name}-{form}"),
+        None => name.to_string(),
+    }
+}
+
+// Showdown names forms with a short suffix, e.g. "Growlithe-Hisui" or "Burmy-Sandy".
+pub fn get_showdown_name(species: u16, form: u16) -> String {
+    let name = SPECIES_EN[species as usize];
+    let form_name = match get_form_name(species, form) {
+        Some(FormName::Prefix(form_name)) | Some(FormName::Suffix(form_name)) => form_name,
+        None if form != 0 => return format!("{name}-{form}"),
+        None => return name.to_string(),
+    };
+    let suffix = match form_name {
+        "Hisuian" => "Hisui",
+        "Alolan" => "Alola",
+        "Female" => "F",
+        "!" => "Exclamation",
+        "?" => "Question",
+        _ => form_name.split(' ').next().unwrap_or(form_name),
+    };
+    format!("{name}-{suffix}")
+}
@@ -1,35 +1,66 @@
+#[cfg(feature = "runtime-tables")]
+use crate::generation::spawn_generator;
 use crate::util::{behavior_util, SPECIES_DICT};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+#[cfg(feature = "runtime-tables")]
 use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-pub fn get_map(json: &str) -> HashMap<u64, Vec<SlotDetail>> {
-    let obj = serde_json::from_str::<HashMap<String, Vec<SlotDetail>>>(json).unwrap();
+#[cfg(feature = "runtime-tables")]
+pub fn try_get_map(json: &str) -> Result<HashMap<u64, Vec<SlotDetail>>, TableError> {
+    let obj = serde_json::from_str::<HashMap<String, Vec<SlotDetail>>>(json)
+        .map_err(|e| TableError::Json(e.to_string()))?;
     let mut result = HashMap::with_capacity(obj.len());
     for (key, mut value) in obj {
-        if let Ok(hash) = u64::from_str_radix(&key.as_str()[2..], 16) {
-            for slot in value.iter_mut() {
-                slot.set_species();
-            }
-            result.insert(hash, value);
+        let hash = parse_hash(&key)
+            .or_else(|| parse_outbreak_key(&key))
+            .ok_or_else(|| TableError::InvalidHash(key.clone()))?;
+        for (index, slot) in value.iter_mut().enumerate() {
+            slot.try_set_species()
+                .map_err(|reason| TableError::InvalidSlot {
+                    table: key.clone(),
+                    index,
+                    reason,
+                })?;
         }
+        result.insert(hash, value);
     }
-    result
+    Ok(result)
+}
+
+// Regular mass outbreak tables are keyed by species name, e.g. "Basculin-2".
+#[cfg(feature = "runtime-tables")]
+pub fn parse_outbreak_key(key: &str) -> Option<u64> {
+    let mut slot = SlotDetail {
+        name: key.to_string().into(),
+        ..SlotDetail::default()
+    };
+    slot.try_set_species().ok()?;
+    Some(spawn_generator::get_outbreak_table(slot.species, slot.form))
+}
+
+#[cfg(feature = "runtime-tables")]
+pub fn parse_hash(key: &str) -> Option<u64> {
+    let hex = key.strip_prefix("0x").or_else(|| key.strip_prefix("0X"))?;
+    u64::from_str_radix(hex, 16).ok()
 }
 
-#[derive(Deserialize, Default, Clone, Debug)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct SlotDetail {
     #[serde(rename = "slot")]
     pub rate: usize,
-    pub name: String,
+    pub name: Cow<'static, str>,
     #[serde(rename = "alpha")]
     pub is_alpha: bool,
     pub level: Option<[usize; 2]>,
     #[serde(rename = "ivs")]
     pub flawless_ivs: usize,
-    #[serde(skip_deserializing)]
+    #[serde(default)]
     pub species: u16,
-    #[serde(skip_deserializing)]
+    #[serde(default)]
     pub form: u16,
 }
 
@@ -55,16 +86,19 @@ impl SlotDetail {
     }
 
     pub fn set_species(&mut self) {
-        let mut species;
-        if let Some(dash) = self.name.as_bytes().iter().position(|c| *c == b'-') {
-            if let Ok(form) = u16::from_str(&self.name.as_str()[(dash + 1)..]) {
+        if let Err(reason) = self.try_set_species() {
+            panic!("{reason}")
+        }
+    }
+
+    pub fn try_set_species(&mut self) -> Result<(), String> {
+        let mut species = self.name.to_string();
+        // Species such as Porygon-Z have a dash in their name, so only a numeric suffix is a form.
+        if let Some(dash) = self.name.as_bytes().iter().rposition(|c| *c == b'-') {
+            if let Ok(form) = u16::from_str(&self.name[(dash + 1)..]) {
                 self.form = form;
                 species = self.name[..dash].to_string();
-            } else {
-                panic!("Invalid number for form")
             }
-        } else {
-            species = self.name.clone();
         }
 
         if species.as_str() == "MimeJr." {
@@ -76,8 +110,46 @@ impl SlotDetail {
 
         if let Some(species) = SPECIES_DICT.get(species.as_str()) {
             self.species = *species;
+            Ok(())
         } else {
-            panic!("No species found!")
+            Err(format!("No species found for {}", self.name))
         }
     }
 }
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TableError {
+    Io {
+        path: String,
+        reason: String,
+    },
+    Json(String),
+    InvalidHash(String),
+    InvalidSlot {
+        table: String,
+        index: usize,
+        reason: String,
+    },
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableError::Io { path, reason } => write!(f, "failed to read {path}: {reason}"),
+            TableError::Json(reason) => write!(f, "malformed table json: {reason}"),
+            TableError::InvalidHash(key) => {
+                write!(
+                    f,
+                    "invalid table key \"{key}\", expected a 0x-prefixed hex value or species name"
+                )
+            }
+            TableError::InvalidSlot {
+                table,
+                index,
+                reason,
+            } => write!(f, "invalid slot {index} in table {table}: {reason}"),
+        }
+    }
+}
+
+impl Error for TableError {}
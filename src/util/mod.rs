@@ -1,9 +1,17 @@
 pub mod area_util;
 pub mod behavior_util;
 pub mod calculations;
+pub mod export_util;
+pub mod form_util;
 pub mod json_decoder;
+pub mod outbreak_odds;
 pub mod permute_dump;
+pub mod save_util;
+pub mod size_util;
 mod spawn_info;
+pub mod stat_util;
+pub mod table_validator;
+pub mod timeline;
 
 use lazy_static::lazy_static;
 use pkhex_rs::game_strings::SPECIES_EN;
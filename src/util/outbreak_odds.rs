@@ -0,0 +1,227 @@
+use crate::generation::{EntityResult, ShinyRollProfile};
+use crate::permutation::{Advance, PermuteMeta};
+use crate::permuter;
+use crate::structure::{
+    MassOutbreakSet8a, MassiveOutbreakArea8a, MassiveOutbreakSet8a, MassiveOutbreakSpawnerStatus,
+};
+use crate::util::{area_util, form_util, SpawnInfo};
+use crate::Xoroshiro;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const MAX_DEPTH: usize = 15;
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct OutbreakOdds {
+    pub samples: usize,
+    pub shiny: usize,
+    pub shiny_alpha: usize,
+    pub target: usize,
+    pub permuted_shiny: usize,
+    pub permuted_shiny_alpha: usize,
+    pub permuted_target: usize,
+}
+
+impl OutbreakOdds {
+    pub fn get_rate(&self, hits: usize) -> f64 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            hits as f64 / self.samples as f64
+        }
+    }
+
+    pub fn get_lines(&self) -> Vec<String> {
+        let line = |name: &str, hits: usize, permuted: usize| {
+            format!(
+                "  {name:<12} {:>6.2}% without permuting, {:>6.2}% with a permuted path",
+                self.get_rate(hits) * 100.0,
+                self.get_rate(permuted) * 100.0
+            )
+        };
+        vec![
+            line("Shiny:", self.shiny, self.permuted_shiny),
+            line("Shiny Alpha:", self.shiny_alpha, self.permuted_shiny_alpha),
+            line("Target:", self.target, self.permuted_target),
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SpawnerOdds {
+    pub index: usize,
+    pub species: u16,
+    pub form: u16,
+    pub odds: OutbreakOdds,
+}
+
+#[derive(Clone, Debug)]
+pub struct AreaOdds {
+    pub area_hash: u64,
+    pub spawners: Vec<SpawnerOdds>,
+}
+
+impl AreaOdds {
+    pub fn get_area_name(&self) -> &'static str {
+        area_util::AREA_TABLE
+            .get(&self.area_hash)
+            .or_else(|| area_util::AREA_TABLE.get(&0))
+            .unwrap()
+    }
+
+    pub fn get_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("Outbreak odds in {}:", self.get_area_name())];
+        for spawner in self.spawners.iter() {
+            lines.push(format!(
+                "Spawner {} shows {} ({} samples)",
+                spawner.index + 1,
+                form_util::get_display_name(spawner.species, spawner.form),
+                spawner.odds.samples
+            ));
+            lines.extend(spawner.odds.get_lines());
+        }
+        lines
+    }
+}
+
+fn permute_criteria(entity: &EntityResult, _advances: &[Advance]) -> bool {
+    entity.is_shiny
+}
+
+// A target is a shiny of the given species, any form. Group seeds are drawn from `seed`, so
+// results are reproducible for the same arguments. Every sample permutes the spawner to depth 15,
+// which makes it by far the slowest part.
+pub fn get_odds(
+    spawner: &Rc<RefCell<SpawnInfo>>,
+    target: Option<u16>,
+    samples: usize,
+    seed: u64,
+    profile: &ShinyRollProfile,
+) -> OutbreakOdds {
+    let is_target = |e: &EntityResult| e.is_shiny && Some(e.species) == target;
+    let mut odds = OutbreakOdds {
+        samples,
+        ..OutbreakOdds::default()
+    };
+    let mut rng = Xoroshiro::new(seed);
+    for _ in 0..samples {
+        let group_seed = rng.next_u64();
+
+        let spawned = play_through(spawner, group_seed, profile);
+        odds.shiny += spawned.iter().any(|e| e.is_shiny) as usize;
+        odds.shiny_alpha += spawned.iter().any(|e| e.is_shiny && e.is_alpha) as usize;
+        odds.target += spawned.iter().any(is_target) as usize;
+
+        let permuted = permuter::permute_with_profile(
+            spawner.clone(),
+            group_seed,
+            MAX_DEPTH,
+            Some(permute_criteria),
+            profile.clone(),
+        );
+        let results = permuted.results.iter().map(|r| &r.entity);
+        odds.permuted_shiny += permuted.has_results() as usize;
+        odds.permuted_shiny_alpha += results.clone().any(|e| e.is_alpha) as usize;
+        odds.permuted_target += results.clone().any(is_target) as usize;
+    }
+    odds
+}
+
+// Every spawn seen when knocking out everything on the field until each wave is exhausted.
+pub fn play_through(
+    spawner: &Rc<RefCell<SpawnInfo>>,
+    group_seed: u64,
+    profile: &ShinyRollProfile,
+) -> Vec<EntityResult> {
+    let mut meta = PermuteMeta::new(spawner.clone(), usize::MAX, |_, _| true, profile.clone());
+
+    let mut seed = group_seed;
+    loop {
+        let table = meta.spawner.borrow().set.table;
+        let mut state = meta.spawner.borrow_mut().get_starting_state();
+        (seed, state) = permuter::update_respawn(&mut meta, table, seed, state);
+        while state.count != 0 && state.alive() != 0 {
+            state = state.remove(
+                state.alive_aggressive,
+                state.alive_beta,
+                state.alive_oblivious,
+            );
+            (seed, state) = permuter::update_respawn(&mut meta, table, seed, state);
+        }
+
+        let (can_continue, next) = meta.attempt_next_wave();
+        if !can_continue {
+            break;
+        }
+        meta.spawner = next;
+    }
+    meta.results.into_iter().map(|r| r.entity).collect()
+}
+
+// Each spawner gets its own seed drawn from `seed`, so their samples aren't correlated.
+pub fn get_massive_outbreak_odds(
+    block: &MassiveOutbreakSet8a,
+    target: Option<u16>,
+    samples: usize,
+    seed: u64,
+    profile: &ShinyRollProfile,
+) -> Vec<AreaOdds> {
+    let mut seeds = Xoroshiro::new(seed);
+    let mut areas = Vec::with_capacity(MassiveOutbreakSet8a::AREA_COUNT);
+    for i in 0..MassiveOutbreakSet8a::AREA_COUNT {
+        let area = block[i];
+        if !area.is_active {
+            continue;
+        }
+
+        let mut spawners = Vec::new();
+        for j in 0..MassiveOutbreakArea8a::SPAWNER_COUNT {
+            let spawner = area[j];
+            if spawner.status() == MassiveOutbreakSpawnerStatus::None {
+                continue;
+            }
+            let spawn: Rc<RefCell<SpawnInfo>> = spawner.into();
+            spawners.push(SpawnerOdds {
+                index: j,
+                species: spawner.display_species,
+                form: spawner.display_form,
+                odds: get_odds(&spawn, target, samples, seeds.next_u64(), profile),
+            });
+        }
+        areas.push(AreaOdds {
+            area_hash: area.area_hash,
+            spawners,
+        });
+    }
+    areas
+}
+
+// Same as `get_massive_outbreak_odds`, one seed per outbreak.
+pub fn get_mass_outbreak_odds(
+    block: &MassOutbreakSet8a,
+    target: Option<u16>,
+    samples: usize,
+    seed: u64,
+    profile: &ShinyRollProfile,
+) -> Vec<AreaOdds> {
+    let mut seeds = Xoroshiro::new(seed);
+    let mut areas = Vec::with_capacity(MassOutbreakSet8a::AREA_COUNT);
+    for i in 0..MassOutbreakSet8a::AREA_COUNT {
+        let spawner = block[i];
+        if !spawner.has_outbreak() {
+            continue;
+        }
+
+        let spawn: Rc<RefCell<SpawnInfo>> = spawner.into();
+        areas.push(AreaOdds {
+            area_hash: spawner.area_hash,
+            spawners: vec![SpawnerOdds {
+                index: 0,
+                species: spawner.display_species,
+                form: spawner.display_form,
+                odds: get_odds(&spawn, target, samples, seeds.next_u64(), profile),
+            }],
+        });
+    }
+    areas
+}
@@ -1,4 +1,4 @@
-use crate::permutation::{AdvanceType, PermuteMeta, PermuteResult};
+use crate::permutation::{Advance, PermuteMeta, PermuteResult};
 use std::collections::BTreeMap;
 
 pub fn dump(meta: PermuteMeta) -> Vec<String> {
@@ -18,9 +18,9 @@ pub fn dump(meta: PermuteMeta) -> Vec<String> {
         let entities = group.1;
         let first = entities.first().unwrap();
         let adv = if step == 0 {
-            AdvanceType::RG
+            Advance::respawn()
         } else {
-            first.advances[step - 1].advance_type
+            first.advances[step - 1]
         };
         for line in get_lines(step, adv, &entities) {
             lines.push(line)
@@ -29,16 +29,16 @@ pub fn dump(meta: PermuteMeta) -> Vec<String> {
     lines
 }
 
-fn get_lines(step: usize, adv: AdvanceType, entities: &[PermuteResult]) -> Vec<String> {
+fn get_lines(step: usize, adv: Advance, entities: &[PermuteResult]) -> Vec<String> {
     let mut lines = Vec::with_capacity(3 + entities.len() * 15 + entities.len() + 1);
 
     lines.push("===================".to_string());
-    lines.push(format!("Step {step}: {}", adv.to_string()));
+    lines.push(format!("Step {step}: {}", adv.short_code()));
     lines.push(
         entities[0]
             .advances
             .iter()
-            .map(|a| a.advance_type.to_string())
+            .map(|a| a.short_code())
             .collect::<Vec<String>>()
             .join("|"),
     );
@@ -0,0 +1,173 @@
+use crate::generation::{ResearchLevel, ResearchSource, ShinyRollProfile};
+use pkhex_rs::SAV8LA;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+const SHINY_CHARM: u16 = 632;
+
+impl ResearchSource for SAV8LA {
+    fn get_research_level(&self, species: u16) -> ResearchLevel {
+        let pokedex = self.get_pokedex();
+        if pokedex.is_perfect(species) {
+            ResearchLevel::Perfect
+        } else if pokedex.is_complete(species) {
+            ResearchLevel::Ten
+        } else {
+            ResearchLevel::None
+        }
+    }
+
+    fn has_shiny_charm(&self) -> bool {
+        self.has_item(SHINY_CHARM)
+    }
+}
+
+// The save is checked block by block before PKHeX reads it, so truncated or corrupt files are
+// reported instead of silently reading as a save without research.
+pub fn get_profile(data: Vec<u8>) -> Result<ShinyRollProfile, SaveError> {
+    get_block_count(&data)?;
+    let sav = SAV8LA::new(data);
+    Ok(ShinyRollProfile::from_research(&sav))
+}
+
+// Walks the decrypted block list: each block is a key followed by a type and data, all xored with
+// a stream seeded from the key.
+pub fn get_block_count(data: &[u8]) -> Result<usize, SaveError> {
+    if data.is_empty() {
+        return Err(SaveError::Empty);
+    }
+
+    let mut reader = BlockReader { data, offset: 0 };
+    let mut count = 0;
+    while reader.offset != data.len() {
+        let key = reader.read_u32()?;
+        let mut xor = BlockXor::new(key);
+        let type_code = reader.read_u8()? ^ xor.next_u8();
+        let size = match type_code {
+            BOOL1..=BOOL3 => 0,
+            OBJECT => (reader.read_u32()? ^ xor.next_u32()) as usize,
+            ARRAY => {
+                let entries = (reader.read_u32()? ^ xor.next_u32()) as usize;
+                let sub_type = reader.read_u8()? ^ xor.next_u8();
+                let size = get_value_size(sub_type).ok_or(SaveError::InvalidType {
+                    key,
+                    type_code: sub_type,
+                })?;
+                entries * size
+            }
+            _ => get_value_size(type_code).ok_or(SaveError::InvalidType { key, type_code })?,
+        };
+        reader.skip(size)?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+const BOOL1: u8 = 1;
+const BOOL3: u8 = 3;
+const OBJECT: u8 = 4;
+const ARRAY: u8 = 5;
+
+fn get_value_size(type_code: u8) -> Option<usize> {
+    match type_code {
+        BOOL1..=BOOL3 | 8 | 12 => Some(1),
+        9 | 13 => Some(2),
+        10 | 14 | 16 => Some(4),
+        11 | 15 | 17 => Some(8),
+        _ => None,
+    }
+}
+
+struct BlockReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl BlockReader<'_> {
+    fn skip(&mut self, size: usize) -> Result<&[u8], SaveError> {
+        let end = self
+            .offset
+            .checked_add(size)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(SaveError::Truncated {
+                offset: self.offset,
+            })?;
+        let bytes = &self.data[self.offset..end];
+        self.offset = end;
+        Ok(bytes)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, SaveError> {
+        Ok(self.skip(1)?[0])
+    }
+
+    fn read_u32(&mut self) -> Result<u32, SaveError> {
+        Ok(u32::from_le_bytes(self.skip(4)?.try_into().unwrap()))
+    }
+}
+
+// The game's xorshift32 key stream, giving one byte of the state at a time.
+pub struct BlockXor {
+    state: u32,
+    counter: u32,
+}
+
+impl BlockXor {
+    pub fn new(key: u32) -> Self {
+        let mut state = key;
+        for _ in 0..key.count_ones() {
+            state = BlockXor::advance(state);
+        }
+        Self { state, counter: 0 }
+    }
+
+    pub fn next_u8(&mut self) -> u8 {
+        let result = (self.state >> (self.counter << 3)) as u8;
+        if self.counter == 3 {
+            self.state = BlockXor::advance(self.state);
+            self.counter = 0;
+        } else {
+            self.counter += 1;
+        }
+        result
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        u32::from_le_bytes([
+            self.next_u8(),
+            self.next_u8(),
+            self.next_u8(),
+            self.next_u8(),
+        ])
+    }
+
+    fn advance(mut state: u32) -> u32 {
+        state ^= state << 2;
+        state ^= state >> 15;
+        state ^= state << 13;
+        state
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SaveError {
+    Empty,
+    Truncated { offset: usize },
+    InvalidType { key: u32, type_code: u8 },
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Empty => write!(f, "save file is empty"),
+            SaveError::Truncated { offset } => {
+                write!(f, "save file ends in the middle of a block at {offset:#X}")
+            }
+            SaveError::InvalidType { key, type_code } => {
+                write!(f, "block {key:08X} has unknown type {type_code}")
+            }
+        }
+    }
+}
+
+impl Error for SaveError {}
@@ -0,0 +1,57 @@
+use pkhex_rs::{personal_table, PersonalInfo};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub enum SizeClass {
+    XXXS,
+    XXS,
+    XS,
+    S,
+    AV,
+    L,
+    XL,
+    XXL,
+    XXXL,
+}
+
+impl From<u8> for SizeClass {
+    fn from(scalar: u8) -> Self {
+        match scalar {
+            0 => SizeClass::XXXS,
+            1..=24 => SizeClass::XXS,
+            25..=59 => SizeClass::XS,
+            60..=99 => SizeClass::S,
+            100..=155 => SizeClass::AV,
+            156..=195 => SizeClass::L,
+            196..=230 => SizeClass::XL,
+            231..=254 => SizeClass::XXL,
+            255 => SizeClass::XXXL,
+        }
+    }
+}
+
+impl Display for SizeClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+pub fn get_height_ratio(height_scalar: u8) -> f32 {
+    (height_scalar as f32 / 255.0) * 0.79999995 + 0.6
+}
+
+pub fn get_weight_ratio(weight_scalar: u8) -> f32 {
+    (weight_scalar as f32 / 255.0) * 0.40000004 + 0.8
+}
+
+pub fn get_height_m(species: u16, form: u16, height_scalar: u8) -> f32 {
+    let personal = personal_table::LA.get_form_entry(species as usize, form as usize);
+    get_height_ratio(height_scalar) * personal.get_height() as f32 / 100.0
+}
+
+pub fn get_weight_kg(species: u16, form: u16, height_scalar: u8, weight_scalar: u8) -> f32 {
+    let personal = personal_table::LA.get_form_entry(species as usize, form as usize);
+    let weight = get_weight_ratio(weight_scalar) * personal.get_weight() as f32;
+    get_height_ratio(height_scalar) * weight / 10.0
+}
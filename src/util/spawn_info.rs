@@ -1,4 +1,4 @@
-use crate::generation::SpawnType;
+use crate::generation::{spawn_generator, SpawnType};
 use crate::structure::{MassOutbreakSpawner8a, MassiveOutbreakSpawner8a};
 use crate::{SpawnState, Xoroshiro};
 use std::cell::RefCell;
@@ -148,7 +148,10 @@ impl From<MassOutbreakSpawner8a> for Rc<RefCell<SpawnInfo>> {
         Rc::new(RefCell::new(SpawnInfo {
             count: SpawnInfo::OUTBREAK,
             set: SpawnSet {
-                table: spawner.display_species as u64,
+                table: spawn_generator::get_outbreak_table(
+                    spawner.display_species,
+                    spawner.display_form,
+                ),
                 count: spawner.base_count as usize,
             },
             spawn_type: SpawnType::Outbreak,
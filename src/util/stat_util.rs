@@ -0,0 +1,67 @@
+use pkhex_rs::{personal_table, PersonalInfo};
+
+const GANBARU_MULTIPLIER: [u8; 11] = [0, 2, 3, 4, 7, 8, 9, 14, 15, 16, 25];
+// Natures modify Atk/Def/Spe/SpA/SpD while IVs and stats are ordered HP/Atk/Def/SpA/SpD/Spe.
+const NATURE_INDEX: [usize; 6] = [usize::MAX, 0, 1, 3, 4, 2];
+
+pub fn get_stats(species: u16, form: u16, level: usize, nature: u8, ivs: &[u8; 6]) -> [u16; 6] {
+    let personal = personal_table::LA.get_form_entry(species as usize, form as usize);
+    let base = [
+        personal.get_hp(),
+        personal.get_atk(),
+        personal.get_def(),
+        personal.get_spa(),
+        personal.get_spd(),
+        personal.get_spe(),
+    ];
+    let mut stats = [0; 6];
+    for (i, stat) in stats.iter_mut().enumerate() {
+        let effort = get_ganbaru_stat(base[i], ivs[i], 0, level);
+        *stat = if i == 0 {
+            get_stat_hp(base[i], level) + effort
+        } else {
+            amplify_stat(nature, NATURE_INDEX[i], get_stat(base[i], level) + effort)
+        } as u16;
+    }
+    stats
+}
+
+pub fn get_ganbaru_bias(iv: u8) -> u8 {
+    match iv {
+        31.. => 3,
+        26..=30 => 2,
+        20..=25 => 1,
+        _ => 0,
+    }
+}
+
+// Matches PKHeX's PA8 calculation, including the game's float precision and banker's rounding.
+pub fn get_ganbaru_stat(base_stat: usize, iv: u8, gv: u8, level: usize) -> usize {
+    let index = (gv + get_ganbaru_bias(iv)).min(10);
+    let multiplier = GANBARU_MULTIPLIER[index as usize] as f64;
+    let step = ((base_stat as f32).sqrt() as f64) * multiplier;
+    let level_factor = (level as f32 / 50.0) + 1.0;
+    (level_factor as f64 * step / 2.5).round_ties_even() as usize
+}
+
+fn get_stat_hp(base_stat: usize, level: usize) -> usize {
+    (((level as f32 / 100.0) + 1.0) * base_stat as f32) as usize + level
+}
+
+fn get_stat(base_stat: usize, level: usize) -> usize {
+    ((((level as f32 / 50.0) + 1.0) * base_stat as f32) / 1.5) as usize
+}
+
+fn amplify_stat(nature: u8, index: usize, initial: usize) -> usize {
+    let up = nature as usize / 5;
+    let down = nature as usize % 5;
+    if up == down {
+        initial
+    } else if index == up {
+        initial * 11 / 10
+    } else if index == down {
+        initial * 9 / 10
+    } else {
+        initial
+    }
+}
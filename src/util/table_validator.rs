@@ -0,0 +1,265 @@
+use crate::generation::encounter_table;
+use crate::util::json_decoder::SlotDetail;
+#[cfg(feature = "runtime-tables")]
+use crate::util::json_decoder::{parse_hash, parse_outbreak_key, TableError};
+use pkhex_rs::game_strings::SPECIES_EN;
+use pkhex_rs::{personal_table, PersonalInfo};
+#[cfg(feature = "runtime-tables")]
+use serde::de::{MapAccess, Visitor};
+#[cfg(feature = "runtime-tables")]
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Display, Formatter};
+
+pub const MIN_ALPHA_FLAWLESS_IVS: usize = 3;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TableIssue {
+    DuplicateHash(String),
+    InvalidKey(String),
+    InvalidSpecies {
+        table: String,
+        index: usize,
+        reason: String,
+    },
+    MissingSpecies {
+        table: String,
+        index: usize,
+        species: u16,
+    },
+    MissingForm {
+        table: String,
+        index: usize,
+        species: u16,
+        form: u16,
+    },
+    MissingLevel {
+        table: String,
+        index: usize,
+    },
+    UnorderedLevel {
+        table: String,
+        index: usize,
+        min: usize,
+        max: usize,
+    },
+    ZeroRate {
+        table: String,
+        index: usize,
+    },
+    AlphaIvs {
+        table: String,
+        index: usize,
+        flawless_ivs: usize,
+    },
+}
+
+impl Display for TableIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableIssue::DuplicateHash(key) => write!(f, "table {key} is defined more than once"),
+            TableIssue::InvalidKey(key) => {
+                write!(f, "table key {key} is neither a hash nor a species name")
+            }
+            TableIssue::InvalidSpecies {
+                table,
+                index,
+                reason,
+            } => write!(f, "{table}[{index}]: {reason}"),
+            TableIssue::MissingSpecies {
+                table,
+                index,
+                species,
+            } => write!(f, "{table}[{index}]: species {species} is not in Legends: Arceus"),
+            TableIssue::MissingForm {
+                table,
+                index,
+                species,
+                form,
+            } => write!(
+                f,
+                "{table}[{index}]: {} has no form {form}",
+                SPECIES_EN[*species as usize]
+            ),
+            TableIssue::MissingLevel { table, index } => {
+                write!(f, "{table}[{index}]: missing level range")
+            }
+            TableIssue::UnorderedLevel {
+                table,
+                index,
+                min,
+                max,
+            } => write!(f, "{table}[{index}]: level range {min}-{max} is reversed"),
+            TableIssue::ZeroRate { table, index } => {
+                write!(f, "{table}[{index}]: slot rate must be positive")
+            }
+            TableIssue::AlphaIvs {
+                table,
+                index,
+                flawless_ivs,
+            } => write!(
+                f,
+                "{table}[{index}]: alpha slot has {flawless_ivs} flawless IVs, expected at least {MIN_ALPHA_FLAWLESS_IVS}"
+            ),
+        }
+    }
+}
+
+pub fn get_key(hash: u64) -> String {
+    format!("0x{hash:016X}")
+}
+
+// Tables keyed by a species name are used for regular outbreaks, which have no level ranges.
+pub fn validate_slots(table: &str, slots: &[SlotDetail], require_level: bool) -> Vec<TableIssue> {
+    slots
+        .iter()
+        .enumerate()
+        .flat_map(|(index, slot)| validate_slot(table, index, slot, require_level))
+        .collect()
+}
+
+pub fn validate_slot(
+    table: &str,
+    index: usize,
+    slot: &SlotDetail,
+    require_level: bool,
+) -> Vec<TableIssue> {
+    let table = table.to_string();
+    let mut issues = Vec::new();
+    if slot.rate == 0 {
+        issues.push(TableIssue::ZeroRate {
+            table: table.clone(),
+            index,
+        });
+    }
+
+    match slot.level {
+        Some([min, max]) if min > max => issues.push(TableIssue::UnorderedLevel {
+            table: table.clone(),
+            index,
+            min,
+            max,
+        }),
+        None if require_level => issues.push(TableIssue::MissingLevel {
+            table: table.clone(),
+            index,
+        }),
+        _ => {}
+    }
+
+    if slot.is_alpha && slot.flawless_ivs < MIN_ALPHA_FLAWLESS_IVS {
+        issues.push(TableIssue::AlphaIvs {
+            table: table.clone(),
+            index,
+            flawless_ivs: slot.flawless_ivs,
+        });
+    }
+
+    if let Some(issue) = validate_species(table, index, slot.species, slot.form) {
+        issues.push(issue);
+    }
+    issues
+}
+
+fn validate_species(table: String, index: usize, species: u16, form: u16) -> Option<TableIssue> {
+    if species == 0 || species as usize >= SPECIES_EN.len() {
+        return Some(TableIssue::MissingSpecies {
+            table,
+            index,
+            species,
+        });
+    }
+
+    let personal = personal_table::LA.get_form_entry(species as usize, 0);
+    if !personal.is_present_in_game() {
+        Some(TableIssue::MissingSpecies {
+            table,
+            index,
+            species,
+        })
+    } else if form as usize >= personal.get_form_count() {
+        Some(TableIssue::MissingForm {
+            table,
+            index,
+            species,
+            form,
+        })
+    } else {
+        None
+    }
+}
+
+pub fn validate_tables() -> Vec<TableIssue> {
+    encounter_table::get_table_hashes()
+        .into_iter()
+        .flat_map(|hash| {
+            let slots = encounter_table::get_table(hash).unwrap_or_default();
+            validate_slots(&get_key(hash), slots, true)
+        })
+        .collect()
+}
+
+#[cfg(feature = "runtime-tables")]
+pub fn validate_json(json: &str) -> Result<Vec<TableIssue>, TableError> {
+    let entries = serde_json::from_str::<TableEntries>(json)
+        .map_err(|e| TableError::Json(e.to_string()))?
+        .0;
+
+    let mut issues = Vec::new();
+    let mut hashes = Vec::with_capacity(entries.len());
+    for (key, mut slots) in entries {
+        let require_level = if let Some(hash) = parse_hash(&key) {
+            if hashes.contains(&hash) {
+                issues.push(TableIssue::DuplicateHash(key.clone()));
+            }
+            hashes.push(hash);
+            true
+        } else {
+            if parse_outbreak_key(&key).is_none() {
+                issues.push(TableIssue::InvalidKey(key.clone()));
+            }
+            false
+        };
+
+        for (index, slot) in slots.iter_mut().enumerate() {
+            if let Err(reason) = slot.try_set_species() {
+                issues.push(TableIssue::InvalidSpecies {
+                    table: key.clone(),
+                    index,
+                    reason,
+                });
+            } else {
+                issues.extend(validate_slot(&key, index, slot, require_level));
+            }
+        }
+    }
+    Ok(issues)
+}
+
+// Keeps every key in file order so duplicate hashes can be reported instead of overwritten.
+#[cfg(feature = "runtime-tables")]
+struct TableEntries(Vec<(String, Vec<SlotDetail>)>);
+
+#[cfg(feature = "runtime-tables")]
+impl<'de> Deserialize<'de> for TableEntries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = TableEntries;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                write!(f, "a map of encounter tables")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(TableEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::generation::EntityResult;
+use crate::permutation::{Advance, AdvanceKind, PermuteResult, SpawnStep};
+
+const HEADERS: [&str; 7] = ["#", "Step", "Slots", "Left", "Seed", "Count Seed", "Spawns"];
+
+struct TimelineRow {
+    step: String,
+    slots: Vec<char>,
+    remaining: usize,
+    seed: u64,
+    count_seed: u64,
+    spawns: Vec<String>,
+}
+
+pub fn get_lines(steps: &[SpawnStep], results: &[PermuteResult]) -> Vec<String> {
+    let rows = get_rows(steps, results);
+    let cells = rows
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            [
+                i.to_string(),
+                row.step.clone(),
+                row.slots.iter().map(|c| format!("[{c}]")).collect(),
+                row.remaining.to_string(),
+                format!("{:0>16X}", row.seed),
+                format!("{:0>16X}", row.count_seed),
+                row.spawns.join(", "),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let mut widths = HEADERS.map(|h| h.chars().count());
+    for row in cells.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut lines = Vec::with_capacity(cells.len() + 2);
+    lines.push(format_line(&HEADERS.map(|h| h.to_string()), &widths));
+    lines.push(
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<String>>()
+            .join("-+-"),
+    );
+    for row in cells.iter() {
+        lines.push(format_line(row, &widths));
+    }
+    lines
+}
+
+pub fn get_html(steps: &[SpawnStep], results: &[PermuteResult]) -> String {
+    let rows = get_rows(steps, results);
+    let mut html = String::from("<table class=\"timeline\">\n<thead><tr>");
+    for header in HEADERS {
+        html.push_str(&format!("<th>{header}</th>"));
+    }
+    html.push_str("</tr></thead>\n<tbody>\n");
+
+    for (i, row) in rows.iter().enumerate() {
+        html.push_str("<tr>");
+        html.push_str(&format!("<td>{i}</td>"));
+        html.push_str(&format!("<td>{}</td>", escape(&row.step)));
+        html.push_str("<td>");
+        for slot in row.slots.iter() {
+            html.push_str(&format!(
+                "<span class=\"slot slot-{}\">{}</span>",
+                get_slot_class(*slot),
+                escape(&slot.to_string())
+            ));
+        }
+        html.push_str("</td>");
+        html.push_str(&format!("<td>{}</td>", row.remaining));
+        html.push_str(&format!("<td>{:0>16X}</td>", row.seed));
+        html.push_str(&format!("<td>{:0>16X}</td>", row.count_seed));
+        html.push_str(&format!(
+            "<td>{}</td>",
+            row.spawns
+                .iter()
+                .map(|s| escape(s))
+                .collect::<Vec<String>>()
+                .join("<br>")
+        ));
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</tbody>\n</table>");
+    html
+}
+
+fn get_rows(steps: &[SpawnStep], results: &[PermuteResult]) -> Vec<TimelineRow> {
+    let mut taken: Vec<Advance> = Vec::with_capacity(steps.len());
+    let mut rows = Vec::with_capacity(steps.len());
+    for step in steps {
+        let mut spawns = Vec::new();
+        if step.step.kind == AdvanceKind::Respawn {
+            let mut indexes = Vec::new();
+            for result in results {
+                if Advance::sequence_eq(&result.advances, &taken)
+                    && !indexes.contains(&result.entity.index)
+                {
+                    indexes.push(result.entity.index);
+                    spawns.push(get_spawn_marker(&result.entity));
+                }
+            }
+        } else {
+            taken.push(step.step);
+        }
+
+        rows.push(TimelineRow {
+            step: step.step.get_name(),
+            slots: step.state.get_state().chars().collect(),
+            remaining: step.state.count,
+            seed: step.seed,
+            count_seed: step.count_seed,
+            spawns,
+        });
+    }
+    rows
+}
+
+fn get_spawn_marker(entity: &EntityResult) -> String {
+    let alpha = if entity.is_alpha { "α-" } else { "" };
+    let shiny = if !entity.is_shiny {
+        ""
+    } else if entity.shiny_xor == 0 {
+        " ■"
+    } else {
+        " *"
+    };
+    format!("{}: {alpha}{}{shiny}", entity.index, entity.get_name())
+}
+
+fn get_slot_class(slot: char) -> &'static str {
+    match slot {
+        'a' => "alpha",
+        'A' => "aggressive",
+        'B' => "skittish",
+        'O' => "oblivious",
+        '~' => "ghost",
+        'X' => "empty",
+        _ => "unknown",
+    }
+}
+
+fn format_line(cells: &[String], widths: &[usize]) -> String {
+    cells
+        .iter()
+        .zip(widths.iter())
+        .map(|(cell, width)| format!("{cell:<width$}"))
+        .collect::<Vec<String>>()
+        .join(" | ")
+        .trim_end()
+        .to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -36,6 +36,18 @@ impl Xoroshiro {
         res
     }
 
+    pub fn next_max_rerolls(&mut self, max: u64) -> (u64, usize) {
+        let mask = Xoroshiro::get_bit_mask(max);
+        let mut rerolls = 0;
+        loop {
+            let res = self.next_u64() & mask;
+            if res < max {
+                return (res, rerolls);
+            }
+            rerolls += 1;
+        }
+    }
+
     fn get_bit_mask(mut x: u64) -> u64 {
         x -= 1;
         x |= x >> 1;
@@ -0,0 +1,40 @@
+use permute_mmo_rs::permutation::{Advance, AdvanceKind, AdvanceType};
+
+#[test]
+fn legacy_codes_round_trip() {
+    for i in 0..20 {
+        let advance_type = AdvanceType::try_from(i).unwrap();
+        let advance = Advance::from(advance_type);
+        assert_eq!(advance.legacy_type(), Some(advance_type));
+        assert_eq!(advance.short_code(), advance_type.to_string());
+    }
+    assert!(AdvanceType::try_from(20).is_err());
+}
+
+#[test]
+fn advances_beyond_four_slots() {
+    let aggressive = Advance::knockout_aggressive(6);
+    assert_eq!(aggressive.legacy_type(), None);
+    assert_eq!(aggressive.short_code(), "A6");
+    assert_eq!(aggressive.advance_count(), 6);
+    assert!(aggressive.is_multi_aggressive());
+
+    let beta = Advance::knockout_beta(5).unwrap();
+    assert_eq!(beta.short_code(), "B5");
+    assert_eq!(beta.get_removals(), (4, 1, 0));
+    assert_eq!(
+        Advance::knockout_oblivious(1).unwrap().get_removals(),
+        (0, 0, 1)
+    );
+    assert!(Advance::knockout_beta(0).is_none());
+    assert!(Advance::knockout_oblivious(0).is_none());
+
+    let mixed = Advance {
+        raw: false,
+        ..Advance::knockout(2, 2, 1)
+    };
+    assert_eq!(mixed.kind, AdvanceKind::Knockout);
+    assert_eq!(mixed.get_name(), "2 Beta + 1 Oblivious + 2 Aggressive");
+    assert_eq!(Advance::ghosts(5).short_code(), "G5");
+    assert_eq!(Advance::scare(6).short_code(), "S6");
+}
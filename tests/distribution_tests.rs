@@ -0,0 +1,173 @@
+use permute_mmo_rs::generation::{encounter_table, spawn_generator, EntityResult};
+use permute_mmo_rs::generation::{ShinyRollProfile, SpawnType};
+use permute_mmo_rs::Xoroshiro;
+use pkhex_rs::RATIO_MAGIC_GENDERLESS;
+
+const TABLE: u64 = 0x7FA3A1DE69BD271E;
+
+// Deviations beyond this many standard deviations fail, keeping false failures around 1 in 10^6.
+const MAX_SIGMA: f64 = 5.0;
+
+// Chi-square critical values at p = 10^-6, indexed by degrees of freedom.
+const CHI_SQUARE_LIMITS: [(usize, f64); 5] =
+    [(1, 23.9), (3, 30.7), (5, 35.9), (24, 72.9), (252, 373.6)];
+
+// Set PERMUTE_STAT_SAMPLES to run with millions of samples, e.g. in release builds.
+fn get_samples() -> usize {
+    std::env::var("PERMUTE_STAT_SAMPLES")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(200_000)
+}
+
+fn get_seeds(seed: u64) -> impl Iterator<Item = u64> {
+    let mut rng = Xoroshiro::new(seed);
+    (0..get_samples()).map(move |_| rng.next_u64())
+}
+
+fn generate(seed: u64, shiny_rolls: usize, flawless: usize, gender_ratio: usize) -> EntityResult {
+    let mut result = EntityResult::default();
+    spawn_generator::generate_pokemon(&mut result, seed, shiny_rolls, flawless, gender_ratio);
+    result
+}
+
+fn assert_rate(name: &str, hits: usize, samples: usize, p: f64) {
+    let expected = samples as f64 * p;
+    let sigma = (samples as f64 * p * (1.0 - p)).sqrt();
+    let deviation = (hits as f64 - expected).abs() / sigma;
+    assert!(
+        deviation <= MAX_SIGMA,
+        "{name}: {hits}/{samples} observed, {expected:.1} expected ({deviation:.2} sigma)"
+    );
+}
+
+fn assert_chi_square(name: &str, counts: &[usize], weights: &[f64]) {
+    let samples = counts.iter().sum::<usize>() as f64;
+    let total = weights.iter().sum::<f64>();
+    let chi_square = counts
+        .iter()
+        .zip(weights)
+        .map(|(count, weight)| {
+            let expected = samples * weight / total;
+            (*count as f64 - expected).powi(2) / expected
+        })
+        .sum::<f64>();
+    let dof = counts.len() - 1;
+    let limit = CHI_SQUARE_LIMITS
+        .iter()
+        .find(|(d, _)| *d == dof)
+        .map(|(_, limit)| *limit)
+        .expect("No chi-square limit for degrees of freedom");
+    assert!(
+        chi_square <= limit,
+        "{name}: chi-square {chi_square:.2} exceeds {limit} with {dof} degrees of freedom"
+    );
+}
+
+#[test]
+fn next_max_is_uniform() {
+    for max in [2u64, 6, 25, 253] {
+        let mut rng = Xoroshiro::new(max);
+        let mut counts = vec![0; max as usize];
+        for _ in 0..get_samples() {
+            counts[rng.next_max(max) as usize] += 1;
+        }
+        assert_chi_square(
+            &format!("next_max({max})"),
+            &counts,
+            &vec![1.0; max as usize],
+        );
+    }
+}
+
+#[test]
+fn shiny_rate_per_roll_count() {
+    for rolls in [1, 4, 13, 32] {
+        let samples = get_samples();
+        let entities = get_seeds(rolls as u64)
+            .map(|seed| generate(seed, rolls, 0, RATIO_MAGIC_GENDERLESS))
+            .filter(|e| e.is_shiny)
+            .collect::<Vec<EntityResult>>();
+        let p = 1.0 - (1.0 - 16.0 / 65536.0f64).powi(rolls as i32);
+        assert_rate(&format!("{rolls} rolls"), entities.len(), samples, p);
+        assert!(entities
+            .iter()
+            .all(|e| e.shiny_xor < 16 && (1..=rolls).contains(&e.roll_count_used)));
+    }
+}
+
+#[test]
+fn flawless_ivs_are_placed_evenly() {
+    for flawless in [1, 3, 4] {
+        let samples = get_samples();
+        let mut counts = [0; 6];
+        for seed in get_seeds(flawless as u64) {
+            let entity = generate(seed, 1, flawless, RATIO_MAGIC_GENDERLESS);
+            assert!(entity.ivs.iter().filter(|iv| **iv == 31).count() >= flawless);
+            for (count, iv) in counts.iter_mut().zip(entity.ivs) {
+                if iv == 31 {
+                    *count += 1;
+                }
+            }
+        }
+
+        let placed = flawless as f64 / 6.0;
+        let p = placed + (1.0 - placed) / 32.0;
+        for (stat, count) in counts.iter().enumerate() {
+            assert_rate(
+                &format!("{flawless} flawless, IV {stat}"),
+                *count,
+                samples,
+                p,
+            );
+        }
+    }
+}
+
+#[test]
+fn gender_ratio_thresholds() {
+    for ratio in [31, 63, 127, 191, 225] {
+        let samples = get_samples();
+        let females = get_seeds(ratio as u64)
+            .filter(|seed| generate(*seed, 1, 0, ratio).gender == 1)
+            .count();
+        assert_rate(
+            &format!("gender ratio {ratio}"),
+            females,
+            samples,
+            (ratio - 1) as f64 / 253.0,
+        );
+    }
+}
+
+#[test]
+fn natures_are_uniform() {
+    let mut counts = [0; 25];
+    for seed in get_seeds(25) {
+        counts[generate(seed, 1, 0, RATIO_MAGIC_GENDERLESS).nature as usize] += 1;
+    }
+    assert_chi_square("natures", &counts, &[1.0; 25]);
+}
+
+#[test]
+fn slots_follow_table_weights() {
+    let profile = ShinyRollProfile::default();
+    let slots = encounter_table::get_table(TABLE).unwrap();
+    for no_alpha in [false, true] {
+        let mut counts = vec![0; slots.len()];
+        for seed in get_seeds(TABLE) {
+            let entity =
+                spawn_generator::generate(0, 0, seed, 0, TABLE, SpawnType::MMO, &profile, no_alpha)
+                    .unwrap();
+            counts[entity.slot_index] += 1;
+        }
+
+        let (counts, weights): (Vec<usize>, Vec<f64>) = counts
+            .into_iter()
+            .zip(slots)
+            .filter(|(_, slot)| !(no_alpha && slot.is_alpha))
+            .map(|(count, slot)| (count, slot.rate as f64))
+            .unzip();
+        assert_chi_square(&format!("slots (no alpha: {no_alpha})"), &counts, &weights);
+    }
+}
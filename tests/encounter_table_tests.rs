@@ -0,0 +1,204 @@
+use permute_mmo_rs::generation::{
+    encounter_table, spawn_generator, EntityResult, ShinyRollProfile, SpawnType,
+};
+use permute_mmo_rs::structure::MassOutbreakSpawner8a;
+use permute_mmo_rs::util::SpawnInfo;
+use pkhex_rs::Species;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const TABLE: u64 = 0x7FA3A1DE69BD271E;
+
+#[test]
+fn table_probabilities_are_normalized() {
+    let slots = encounter_table::get_table_probabilities(TABLE, false).unwrap();
+    assert_eq!(slots.len(), 4);
+    let total: f32 = slots.iter().map(|s| s.probability).sum();
+    assert!((total - 1.0).abs() < 0.0001);
+    assert!((slots[0].probability - 100.0 / 112.0).abs() < 0.0001);
+    assert_eq!(slots[1].slot.min_level(), 75);
+    assert_eq!(slots[1].slot.max_level(), 77);
+    assert_eq!(slots[1].slot.flawless_ivs, 3);
+
+    let no_alpha = encounter_table::get_table_probabilities(TABLE, true).unwrap();
+    assert_eq!(no_alpha.len(), 2);
+    assert!(no_alpha.iter().all(|s| !s.slot.is_alpha));
+    assert!((no_alpha[1].probability - 10.0 / 110.0).abs() < 0.0001);
+
+    assert!(encounter_table::get_table_probabilities(0, false).is_none());
+}
+
+#[test]
+fn species_reverse_index() {
+    let tables = encounter_table::get_tables_containing(Species::Pikachu as u16, 0);
+    assert!(tables.contains(&TABLE));
+    assert!(tables.windows(2).all(|w| w[0] < w[1]));
+    for table in &tables {
+        let slots = encounter_table::get_table(*table).unwrap();
+        assert!(slots.iter().any(|s| s.species == Species::Pikachu as u16));
+    }
+    assert!(
+        encounter_table::get_tables_containing_species(Species::Raichu as u16).contains(&TABLE)
+    );
+    assert!(encounter_table::get_tables_containing(Species::Pikachu as u16, 99).is_empty());
+
+    let index = encounter_table::get_species_index();
+    assert!(std::sync::Arc::ptr_eq(
+        &index,
+        &encounter_table::get_species_index()
+    ));
+}
+
+#[test]
+fn precompiled_cumulative_weights() {
+    let table = encounter_table::get_encounter_table(TABLE).unwrap();
+    assert_eq!(table.hash, TABLE);
+    assert_eq!(table.cumulative, &[100.0, 101.0, 111.0, 112.0]);
+    assert_eq!(table.cumulative_no_alpha, &[100.0, 100.0, 110.0, 110.0]);
+    assert_eq!(table.get_rate_sum(true), 110.0);
+
+    assert!(table.get_slot(100.5, false).unwrap().is_alpha);
+    let slot = table.get_slot(100.5, true).unwrap();
+    assert_eq!(slot.species, Species::Raichu as u16);
+    assert!(!slot.is_alpha);
+    assert!(table.get_slot(112.5, false).is_none());
+
+    for hash in encounter_table::get_table_hashes() {
+        let table = encounter_table::get_encounter_table(hash).unwrap();
+        assert_eq!(
+            table.cumulative,
+            encounter_table::get_cumulative_sums(table.slots, false)
+        );
+        assert_eq!(
+            table.cumulative_no_alpha,
+            encounter_table::get_cumulative_sums(table.slots, true)
+        );
+    }
+}
+
+#[test]
+fn entities_reference_table_slots() {
+    let outbreak = spawn_generator::get_spawn_table(Species::Pikachu as u16 as u64);
+    assert!(std::ptr::eq(
+        outbreak,
+        spawn_generator::get_spawn_table(Species::Pikachu as u16 as u64)
+    ));
+    assert_eq!(outbreak.slots.len(), 2);
+
+    let profile = ShinyRollProfile::default();
+    for i in 0..50 {
+        let entity =
+            spawn_generator::generate(0, i, i as u64, 0, TABLE, SpawnType::MMO, &profile, false)
+                .unwrap();
+        assert_eq!(entity.table, TABLE);
+        let slot = entity.get_slot().unwrap();
+        assert!(std::ptr::eq(
+            slot,
+            &encounter_table::get_table(TABLE).unwrap()[entity.slot_index]
+        ));
+        assert_eq!(slot.species, entity.species);
+        assert_eq!(slot.is_alpha, entity.is_alpha);
+    }
+}
+
+#[test]
+fn outbreak_tables_use_display_form() {
+    let table = spawn_generator::get_outbreak_table(Species::Basculin as u16, 2);
+    assert!(spawn_generator::is_outbreak_table(table));
+    let outbreak = spawn_generator::get_spawn_table(table);
+    assert!(outbreak
+        .slots
+        .iter()
+        .all(|s| s.species == Species::Basculin as u16 && s.form == 2));
+    assert_eq!(outbreak.slots[0].name, "Basculin-2");
+
+    let spawner = MassOutbreakSpawner8a {
+        display_species: Species::Growlithe as u16,
+        display_form: 1,
+        base_count: 10,
+        ..MassOutbreakSpawner8a::default()
+    };
+    let spawn: Rc<RefCell<SpawnInfo>> = spawner.into();
+    let table = spawn.borrow().set.table;
+    let entity = spawn_generator::generate(
+        0,
+        1,
+        0x1234,
+        0,
+        table,
+        SpawnType::Outbreak,
+        &ShinyRollProfile::default(),
+        false,
+    )
+    .unwrap();
+    assert_eq!(entity.species, Species::Growlithe as u16);
+    assert_eq!(entity.form, 1);
+    assert_eq!(entity.get_slot().unwrap().name, "Growlithe-1");
+}
+
+#[test]
+fn outbreak_tables_use_massive_outbreak_levels() {
+    assert_eq!(
+        spawn_generator::get_outbreak_level(Species::Pikachu as u16, 0, false),
+        Some([60, 64])
+    );
+    assert_eq!(
+        spawn_generator::get_outbreak_level(Species::Pikachu as u16, 0, true),
+        Some([75, 79])
+    );
+    assert_eq!(
+        spawn_generator::get_outbreak_level(Species::Arceus as u16, 0, false),
+        None
+    );
+
+    let table = spawn_generator::get_outbreak_table(Species::Pikachu as u16, 0);
+    let profile = ShinyRollProfile::default();
+    let mut seen = [false; 2];
+    for i in 0..200 {
+        let entity = spawn_generator::generate(
+            0,
+            i,
+            i as u64 * 0x9E37_79B9,
+            i as u64,
+            table,
+            SpawnType::Outbreak,
+            &profile,
+            false,
+        )
+        .unwrap();
+        let range = if entity.is_alpha { 75..=79 } else { 60..=64 };
+        assert!(range.contains(&entity.level), "{}", entity.level);
+        seen[entity.is_alpha as usize] = true;
+    }
+    assert!(seen[0]);
+
+    let unknown = spawn_generator::get_outbreak_table(Species::Arceus as u16, 0);
+    let entity = spawn_generator::generate(
+        0,
+        1,
+        0x1234,
+        0,
+        unknown,
+        SpawnType::Outbreak,
+        &profile,
+        false,
+    )
+    .unwrap();
+    assert_eq!(entity.level, 0);
+}
+
+#[test]
+fn unknown_tables_have_no_slot() {
+    let entity = EntityResult {
+        table: 0x0123_4567_89AB_CDEF,
+        ..EntityResult::default()
+    };
+    assert!(entity.get_slot().is_none());
+
+    let entity = EntityResult {
+        table: TABLE,
+        slot_index: 99,
+        ..EntityResult::default()
+    };
+    assert!(entity.get_slot().is_none());
+}
@@ -0,0 +1,78 @@
+use permute_mmo_rs::generation::{spawn_generator, GenerateOptions};
+use permute_mmo_rs::util::export_util::{self, ExportError};
+use pkhex_rs::Species;
+
+#[test]
+fn export_pa8() {
+    let species = Species::Growlithe as u16;
+    let entity = spawn_generator::generate_from_seed(
+        0x5EED,
+        &GenerateOptions::new(species, 1, 30).alpha(0xA1FA),
+    );
+    let pkm = export_util::get_pa8(&entity).unwrap();
+    assert_eq!(pkm.get_species(), species);
+    assert_eq!(pkm.get_pid(), entity.pid);
+
+    let name = export_util::get_pa8_file_name(&entity);
+    assert!(name.starts_with("0058"));
+    assert!(name.contains("Hisuian Growlithe"));
+    assert!(name.ends_with(&format!("{:08X}{:08X}.pa8", entity.ec, entity.pid)));
+
+    let dir = std::env::temp_dir();
+    let path = export_util::write_pa8(&entity, &dir).unwrap();
+    assert_eq!(
+        std::fs::read(&path).unwrap(),
+        export_util::get_pa8_data(&entity).unwrap()
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn export_requires_level() {
+    let entity = spawn_generator::generate_from_seed(
+        0x5EED,
+        &GenerateOptions::new(Species::Growlithe as u16, 1, 0),
+    );
+    assert_eq!(entity.level, 0);
+    assert!(matches!(
+        export_util::get_pa8(&entity),
+        Err(ExportError::UnknownLevel)
+    ));
+    let dir = std::env::temp_dir().join("permute_unknown_level");
+    assert_eq!(
+        export_util::write_pa8(&entity, &dir),
+        Err(ExportError::UnknownLevel)
+    );
+    assert!(!dir.exists());
+}
+
+#[test]
+fn export_showdown() {
+    let species = Species::Growlithe as u16;
+    let mut entity = spawn_generator::generate_from_seed(
+        0x5EED,
+        &GenerateOptions::new(species, 1, 30).alpha(0xA1FA),
+    );
+    entity.is_shiny = true;
+    entity.gender = 1;
+    entity.ivs = [31, 0, 31, 12, 31, 31];
+    let text = export_util::get_showdown_text(&entity);
+    let lines = text.lines().collect::<Vec<&str>>();
+    assert_eq!(lines[0], "Growlithe-Hisui (F)");
+    assert!(lines.contains(&"Level: 30"));
+    assert!(lines.contains(&"Shiny: Yes"));
+    assert!(lines.contains(&"Alpha: Yes"));
+    assert!(lines.contains(&"IVs: 0 Atk / 12 SpA"));
+    assert_eq!(
+        lines.iter().filter(|l| l.starts_with("- ")).count(),
+        entity.moves.iter().filter(|m| **m != 0).count()
+    );
+
+    entity.level = 0;
+    entity.gender = 2;
+    entity.ivs = [31; 6];
+    let text = export_util::get_showdown_text(&entity);
+    assert!(text.starts_with("Growlithe-Hisui\n"));
+    assert!(!text.contains("Level:"));
+    assert!(!text.contains("IVs:"));
+}
@@ -0,0 +1,31 @@
+use permute_mmo_rs::util::form_util::{self, FormName};
+use pkhex_rs::Species;
+
+#[test]
+fn form_display_names() {
+    let name = |species: Species, form| form_util::get_display_name(species as u16, form);
+    assert_eq!(name(Species::Growlithe, 0), "Growlithe");
+    assert_eq!(name(Species::Growlithe, 1), "Hisuian Growlithe");
+    assert_eq!(name(Species::Basculin, 2), "White-Striped Basculin");
+    assert_eq!(name(Species::Kleavor, 1), "Kleavor (Lord)");
+    assert_eq!(name(Species::Rotom, 4), "Fan Rotom");
+    assert_eq!(name(Species::Enamorus, 1), "Enamorus (Therian)");
+    assert_eq!(name(Species::Unown, 27), "Unown (?)");
+    assert_eq!(name(Species::Pikachu, 3), "Pikachu-3");
+    assert_eq!(
+        form_util::get_form_name(Species::Shellos as u16, 1),
+        Some(FormName::Suffix("East Sea"))
+    );
+}
+
+#[test]
+fn form_showdown_names() {
+    let name = |species: Species, form| form_util::get_showdown_name(species as u16, form);
+    assert_eq!(name(Species::Growlithe, 0), "Growlithe");
+    assert_eq!(name(Species::Growlithe, 1), "Growlithe-Hisui");
+    assert_eq!(name(Species::Vulpix, 1), "Vulpix-Alola");
+    assert_eq!(name(Species::Basculin, 2), "Basculin-White-Striped");
+    assert_eq!(name(Species::Burmy, 1), "Burmy-Sandy");
+    assert_eq!(name(Species::Basculegion, 1), "Basculegion-F");
+    assert_eq!(name(Species::Unown, 26), "Unown-Exclamation");
+}
@@ -64,34 +64,13 @@ fn test_forwards() {
     );
     let mut result = permuter::permute(spawner, seed, 15, None);
     let seq = vec![
-        Advance {
-            advance_type: AdvanceType::A1,
-            raw: true,
-        },
-        Advance {
-            advance_type: AdvanceType::A1,
-            raw: true,
-        },
-        Advance {
-            advance_type: AdvanceType::A2,
-            raw: true,
-        },
-        Advance {
-            advance_type: AdvanceType::A4,
-            raw: true,
-        },
-        Advance {
-            advance_type: AdvanceType::CR,
-            raw: true,
-        },
-        Advance {
-            advance_type: AdvanceType::A2,
-            raw: true,
-        },
-        Advance {
-            advance_type: AdvanceType::A2,
-            raw: true,
-        },
+        Advance::from(AdvanceType::A1),
+        Advance::from(AdvanceType::A1),
+        Advance::from(AdvanceType::A2),
+        Advance::from(AdvanceType::A4),
+        Advance::from(AdvanceType::CR),
+        Advance::from(AdvanceType::A2),
+        Advance::from(AdvanceType::A2),
     ];
 
     let _temp = Advance::run_forwards(&mut result, &seq, seed);
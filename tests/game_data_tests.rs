@@ -0,0 +1,56 @@
+use permute_mmo_rs::generation::{move_generator, EntityResult};
+use permute_mmo_rs::util::size_util::{
+    get_height_m, get_height_ratio, get_weight_kg, get_weight_ratio,
+};
+use permute_mmo_rs::util::stat_util::get_stats;
+use pkhex_rs::game_strings::{ABILITIES_EN, MOVES_EN, SPECIES_EN};
+use pkhex_rs::Species;
+
+// Checks the game data read from pkhex_rs against known values for Pikachu, so a pkhex_rs update
+// that changes its tables or API fails here first.
+const PIKACHU: u16 = Species::Pikachu as u16;
+const STATIC: u16 = 9;
+const LIGHTNING_ROD: u16 = 31;
+const THUNDER_SHOCK: u16 = 84;
+
+#[test]
+fn species_and_move_names() {
+    assert_eq!(SPECIES_EN[PIKACHU as usize], "Pikachu");
+    assert_eq!(ABILITIES_EN[STATIC as usize], "Static");
+    assert_eq!(ABILITIES_EN[LIGHTNING_ROD as usize], "Lightning Rod");
+    assert_eq!(MOVES_EN[THUNDER_SHOCK as usize], "Thunder Shock");
+}
+
+#[test]
+fn pikachu_abilities() {
+    let ability = |ability| EntityResult {
+        species: PIKACHU,
+        ability,
+        ..EntityResult::default()
+    };
+    assert_eq!(ability(0).get_ability_id(), STATIC);
+    assert_eq!(ability(0).get_ability_name(), "Static");
+    assert_eq!(ability(1).get_ability_id(), LIGHTNING_ROD);
+    assert!(ability(1).has_ability_name("lightning rod"));
+}
+
+#[test]
+fn pikachu_base_stats_and_size() {
+    // Base stats 35/55/40/50/50/90 at level 100 with no IVs or effort levels.
+    assert_eq!(
+        get_stats(PIKACHU, 0, 100, 0, &[0; 6]),
+        [170, 110, 80, 100, 100, 180]
+    );
+
+    // 0.4 m and 6.0 kg before scaling.
+    let height = get_height_m(PIKACHU, 0, 128) / get_height_ratio(128);
+    assert!((height - 0.4).abs() < 1e-5);
+    let weight =
+        get_weight_kg(PIKACHU, 0, 128, 128) / get_height_ratio(128) / get_weight_ratio(128);
+    assert!((weight - 6.0).abs() < 1e-4);
+}
+
+#[test]
+fn pikachu_learns_thunder_shock() {
+    assert!(move_generator::get_moves(PIKACHU, 0, 1).contains(&THUNDER_SHOCK));
+}
@@ -0,0 +1,159 @@
+use permute_mmo_rs::generation::{spawn_generator, GenerateOptions, ShinyRollProfile, SpawnType};
+use permute_mmo_rs::{TracedRng, Xoroshiro};
+use pkhex_rs::{Species, RATIO_MAGIC_FEMALE};
+
+#[test]
+fn generate_from_seed_matches_spawner() {
+    let profile = ShinyRollProfile::default();
+    let spawned = spawn_generator::generate(
+        0,
+        1,
+        0xA5D779D8831721FD,
+        0x1234,
+        0x7FA3A1DE69BD271E,
+        SpawnType::MMO,
+        &profile,
+        false,
+    )
+    .unwrap();
+
+    let mut options = GenerateOptions::new(spawned.species, spawned.form, spawned.level)
+        .shiny_rolls(profile.get_rolls(spawned.species, SpawnType::MMO))
+        .flawless_ivs(spawned.flawless_ivs);
+    if spawned.is_alpha {
+        options = options.alpha(spawned.alpha_seed);
+    }
+    let entity = spawn_generator::generate_from_seed(spawned.gen_seed, &options);
+    assert_eq!(entity.pid, spawned.pid);
+    assert_eq!(entity.ec, spawned.ec);
+    assert_eq!(entity.ivs, spawned.ivs);
+    assert_eq!(entity.nature, spawned.nature);
+    assert_eq!(entity.moves, spawned.moves);
+    assert!(entity.get_slot().is_none());
+    assert!(spawned.get_slot().is_some());
+}
+
+#[test]
+fn generate_from_seed_options() {
+    let species = Species::Pikachu as u16;
+    let entity = spawn_generator::generate_from_seed(
+        0x5EED,
+        &GenerateOptions::new(species, 0, 50)
+            .flawless_ivs(6)
+            .gender_ratio(RATIO_MAGIC_FEMALE),
+    );
+    assert_eq!(entity.species, species);
+    assert_eq!(entity.level, 50);
+    assert_eq!(entity.ivs, [31; 6]);
+    assert_eq!(entity.gender, 1);
+    assert!(!entity.is_alpha);
+
+    let alpha = spawn_generator::generate_from_seed(
+        0x5EED,
+        &GenerateOptions::new(species, 0, 50).alpha(0xA1FA),
+    );
+    assert!(alpha.is_alpha);
+    assert_eq!(alpha.flawless_ivs, 3);
+    assert!(alpha.ivs.iter().filter(|iv| **iv == 31).count() >= 3);
+    assert_eq!(alpha.height, u8::MAX);
+
+    let shiny = (0..2000u64)
+        .map(|seed| {
+            spawn_generator::generate_from_seed(
+                seed,
+                &GenerateOptions::new(species, 0, 50).shiny_rolls(32),
+            )
+        })
+        .filter(|e| e.is_shiny)
+        .count();
+    assert!(shiny > 0);
+}
+
+#[test]
+fn generate_traced_matches_generate() {
+    let profile = ShinyRollProfile::default();
+    let args = (0xA5D779D8831721FD, 0x1234, 0x7FA3A1DE69BD271E);
+    let spawned = spawn_generator::generate(
+        0,
+        1,
+        args.0,
+        args.1,
+        args.2,
+        SpawnType::MMO,
+        &profile,
+        false,
+    )
+    .unwrap();
+    let traced = spawn_generator::generate_traced(
+        0,
+        1,
+        args.0,
+        args.1,
+        args.2,
+        SpawnType::MMO,
+        &profile,
+        false,
+    )
+    .unwrap();
+    assert!(spawned.trace.is_none());
+    assert_eq!(traced.pid, spawned.pid);
+    assert_eq!(traced.ivs, spawned.ivs);
+    assert_eq!(traced.height, spawned.height);
+
+    let trace = traced.trace.as_ref().unwrap();
+    let find = |label: &str| trace.iter().find(|d| d.label == label).unwrap();
+    assert_eq!(trace[0].label, "slot roll");
+    assert_eq!(trace[0].seed0, args.0);
+    assert_eq!(find("gen seed").value, traced.gen_seed);
+    assert_eq!(find("EC").value, traced.ec as u64);
+    assert_eq!(find("fake TID").value, traced.fake_tid as u64);
+    assert_eq!(find("nature").value, traced.nature as u64);
+    let pid_rolls = if traced.is_shiny {
+        traced.roll_count_used
+    } else {
+        profile.get_rolls(traced.species, SpawnType::MMO)
+    };
+    assert_eq!(trace.iter().filter(|d| d.label == "PID").count(), pid_rolls);
+    assert_eq!(
+        trace.last().unwrap().label,
+        if traced.is_alpha { "nature" } else { "weight" }
+    );
+    assert_eq!(traced.get_trace_lines().len(), trace.len());
+}
+
+#[test]
+fn traced_rng_matches_xoroshiro() {
+    let seed = 0xA5D779D8831721FD;
+    let mut rng = Xoroshiro::new(seed);
+    let mut untraced = TracedRng::new(seed, false);
+    let mut traced = TracedRng::new(seed, true);
+    for _ in 0..4 {
+        let expected = rng.next_f32(112.0, 0.0);
+        assert_eq!(untraced.next_f32("slot roll", 112.0, 0.0), expected);
+        assert_eq!(traced.next_f32("slot roll", 112.0, 0.0), expected);
+        let expected = rng.next_u64();
+        assert_eq!(untraced.next_u64("gen seed", 0), expected);
+        assert_eq!(traced.next_u64("gen seed", 0), expected);
+    }
+    assert!(untraced.draws.is_none());
+
+    let draws = traced.draws.unwrap();
+    assert_eq!(draws.len(), 8);
+    let mut raw = Xoroshiro::new(seed);
+    assert_eq!(draws[0].seed0, seed);
+    assert_eq!(draws[0].value, raw.next_u64());
+    assert_eq!(draws[1].seed0, raw.seed0);
+}
+
+#[test]
+fn stats_need_a_level() {
+    let species = Species::Pikachu as u16;
+    let has_stats = |level| {
+        spawn_generator::generate_from_seed(0x5EED, &GenerateOptions::new(species, 0, level))
+            .get_lines()
+            .iter()
+            .any(|line| line.trim_start().starts_with("Stats:"))
+    };
+    assert!(has_stats(30));
+    assert!(!has_stats(0));
+}
@@ -0,0 +1,83 @@
+use permute_mmo_rs::generation::{move_generator, EntityResult};
+use permute_mmo_rs::Xoroshiro;
+use pkhex_rs::learnset::MOVE_SHOP_LA;
+use pkhex_rs::{personal_table, Species};
+
+const PIKACHU: u16 = Species::Pikachu as u16;
+
+fn get_alpha(alpha_seed: u64, level: usize) -> EntityResult {
+    let mut result = EntityResult {
+        species: PIKACHU,
+        level,
+        is_alpha: true,
+        alpha_seed,
+        ..EntityResult::default()
+    };
+    move_generator::set_moves(&mut result);
+    result
+}
+
+#[test]
+fn alpha_move_is_one_draw_from_alpha_seed() {
+    let moves = move_generator::get_moves(PIKACHU, 0, 30);
+    let personal = personal_table::LA.get_form_entry(PIKACHU as usize, 0);
+    let candidates = MOVE_SHOP_LA
+        .iter()
+        .enumerate()
+        .filter(|(i, m)| personal.is_move_shop(*i) && !moves.contains(m))
+        .map(|(_, m)| *m)
+        .collect::<Vec<u16>>();
+    assert!(!candidates.is_empty());
+
+    for seed in [0, 0x1234, 0xCAFE_BABE_DEAD_BEEF, u64::MAX] {
+        let mut rng = Xoroshiro::new(seed);
+        let expected = candidates[rng.next_max(candidates.len() as u64) as usize];
+        assert_eq!(
+            move_generator::get_alpha_move(seed, PIKACHU, 0, &moves),
+            expected
+        );
+        assert_eq!(get_alpha(seed, 30).alpha_move, expected);
+    }
+}
+
+#[test]
+fn alpha_move_takes_first_slot() {
+    assert_eq!(
+        move_generator::get_alpha_moves(500, &[1, 2, 3, 4]),
+        [500, 4, 3, 2]
+    );
+    assert_eq!(
+        move_generator::get_alpha_moves(500, &[1, 2, 0, 0]),
+        [500, 2, 1, 0]
+    );
+    assert_eq!(
+        move_generator::get_alpha_moves(500, &[1, 500, 3, 0]),
+        [500, 3, 1, 0]
+    );
+
+    let moves = move_generator::get_moves(PIKACHU, 0, 60);
+    assert!(moves.iter().all(|m| *m != 0));
+    for seed in 0..20 {
+        let result = get_alpha(seed, 60);
+        assert_ne!(result.alpha_move, 0);
+        assert_eq!(result.moves[0], result.alpha_move);
+        assert_eq!(result.moves[1..], [moves[3], moves[2], moves[1]]);
+    }
+}
+
+#[test]
+fn unknown_level_has_no_level_up_moves() {
+    assert_eq!(move_generator::get_moves(PIKACHU, 0, 0), [0; 4]);
+
+    let alpha = get_alpha(0x1234, 0);
+    assert_ne!(alpha.alpha_move, 0);
+    assert_eq!(alpha.moves, [alpha.alpha_move, 0, 0, 0]);
+
+    let mut result = EntityResult {
+        species: PIKACHU,
+        ..EntityResult::default()
+    };
+    move_generator::set_moves(&mut result);
+    assert_eq!(result.alpha_move, 0);
+    assert_eq!(result.moves, [0; 4]);
+}
@@ -0,0 +1,44 @@
+use permute_mmo_rs::generation::{spawn_generator, ShinyRollProfile};
+use permute_mmo_rs::util::{outbreak_odds, SpawnInfo};
+use pkhex_rs::Species;
+
+const BASE_TABLE: u64 = 0x7FA3A1DE69BD271E;
+const BONUS_TABLE: u64 = 0x44182B854CD3745D;
+
+#[test]
+fn play_through_spawns_every_wave() {
+    let profile = ShinyRollProfile::default();
+    let spawner = SpawnInfo::get_mmo(BASE_TABLE, 10, BONUS_TABLE, 6);
+    let spawned = outbreak_odds::play_through(&spawner, 0xA5D779D8831721FD, &profile);
+    assert_eq!(spawned.len(), 16);
+    assert!(spawned[..10].iter().all(|e| e.table == BASE_TABLE));
+    assert!(spawned[10..].iter().all(|e| e.table == BONUS_TABLE));
+
+    let table = spawn_generator::get_outbreak_table(Species::Pikachu as u16, 0);
+    let spawner = SpawnInfo::get_mo(table, 8);
+    let spawned = outbreak_odds::play_through(&spawner, 0x1234, &profile);
+    assert_eq!(spawned.len(), 8);
+}
+
+#[test]
+fn outbreak_odds_are_reproducible() {
+    let profile = ShinyRollProfile::default();
+    let spawner = SpawnInfo::get_mmo(BASE_TABLE, 10, BONUS_TABLE, 6);
+    let target = Some(Species::Pikachu as u16);
+    let odds = outbreak_odds::get_odds(&spawner, target, 8, 0x5EED, &profile);
+    assert_eq!(odds.samples, 8);
+    assert!(odds.shiny_alpha <= odds.shiny && odds.shiny <= odds.samples);
+    assert!(odds.target <= odds.shiny);
+    assert!(odds.permuted_shiny_alpha <= odds.permuted_shiny);
+    assert!(odds.permuted_target <= odds.permuted_shiny);
+    assert_eq!(odds.get_lines().len(), 3);
+    assert_eq!(
+        odds,
+        outbreak_odds::get_odds(&spawner, target, 8, 0x5EED, &profile)
+    );
+
+    let untargeted = outbreak_odds::get_odds(&spawner, None, 8, 0x5EED, &profile);
+    assert_eq!(untargeted.target, 0);
+    assert_eq!(untargeted.permuted_target, 0);
+    assert_eq!(untargeted.permuted_shiny, odds.permuted_shiny);
+}
@@ -0,0 +1,63 @@
+use permute_mmo_rs::permutation::{Advance, AdvanceType, PermuteResult, SpawnStep};
+use permute_mmo_rs::permuter;
+use permute_mmo_rs::util::SpawnInfo;
+
+#[test]
+fn permute_results_round_trip() {
+    let seed = 0xA5D779D8831721FD;
+    let spawner = SpawnInfo::get_mmo(0x7FA3A1DE69BD271E, 10, 0x44182B854CD3745D, 6);
+    let result = permuter::permute(spawner, seed, 15, None);
+    assert!(result.has_results());
+
+    let json = serde_json::to_string(&result.results).unwrap();
+    let parsed: Vec<PermuteResult> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.len(), result.results.len());
+    for (a, b) in result.results.iter().zip(parsed.iter()) {
+        assert!(Advance::sequence_eq(&a.advances, &b.advances));
+        assert_eq!(a.entity.pid, b.entity.pid);
+        assert_eq!(a.entity.group_seed, b.entity.group_seed);
+        assert_eq!(a.entity.slot_roll, b.entity.slot_roll);
+        assert_eq!(a.entity.table, b.entity.table);
+        assert_eq!(a.entity.slot_index, b.entity.slot_index);
+        assert_eq!(a.entity.get_summary(), b.entity.get_summary());
+    }
+}
+
+#[test]
+fn spawn_steps_round_trip() {
+    let seed = 1911689355633755303u64;
+    let spawner = SpawnInfo::get_mmo(0xECBF77B8F7302126, 9, 0x9D713CCF138FD43C, 7);
+    let mut result = permuter::permute(spawner, seed, 15, None);
+    let seq = [AdvanceType::A1, AdvanceType::A2, AdvanceType::CR].map(Advance::from);
+    let steps = Advance::run_forwards(&mut result, &seq, seed);
+
+    let json = serde_json::to_string(&steps).unwrap();
+    let parsed: Vec<SpawnStep> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        steps
+            .iter()
+            .map(SpawnStep::step_summary)
+            .collect::<Vec<_>>(),
+        parsed
+            .iter()
+            .map(SpawnStep::step_summary)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn advance_schema() {
+    let value = serde_json::to_value(Advance::from(AdvanceType::B3)).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "kind": "knockout",
+            "aggressive": 2,
+            "skittish": 1,
+            "oblivious": 0,
+            "ghosts": 0,
+            "raw": true
+        })
+    );
+    assert_eq!(serde_json::to_value(AdvanceType::G2).unwrap(), "G2");
+}
@@ -0,0 +1,122 @@
+use permute_mmo_rs::generation::{ResearchLevel, ResearchSource, ShinyRollProfile, SpawnType};
+use permute_mmo_rs::permuter;
+use permute_mmo_rs::util::save_util::{self, BlockXor, SaveError};
+use permute_mmo_rs::util::SpawnInfo;
+use pkhex_rs::Species;
+
+#[test]
+fn default_profile_matches_spawn_types() {
+    let profile = ShinyRollProfile::default();
+    let species = Species::Pikachu as u16;
+    assert_eq!(profile.get_rolls(species, SpawnType::Regular), 7);
+    assert_eq!(profile.get_rolls(species, SpawnType::MMO), 19);
+    assert_eq!(profile.get_rolls(species, SpawnType::Outbreak), 32);
+}
+
+#[test]
+fn research_levels_change_rolls() {
+    let mut profile = ShinyRollProfile::new(ResearchLevel::None, false);
+    profile.set_research(Species::Raichu as u16, ResearchLevel::Ten);
+    assert_eq!(
+        profile.get_rolls(Species::Pikachu as u16, SpawnType::Regular),
+        1
+    );
+    assert_eq!(
+        profile.get_rolls(Species::Raichu as u16, SpawnType::MMO),
+        14
+    );
+
+    let seed = 0xA5D779D8831721FD;
+    let spawner = SpawnInfo::get_mmo(0x7FA3A1DE69BD271E, 10, 0x44182B854CD3745D, 6);
+    let perfect = permuter::permute(spawner.clone(), seed, 15, None);
+    let unresearched = permuter::permute_with_profile(spawner, seed, 15, None, profile);
+    assert!(unresearched.results.len() <= perfect.results.len());
+    assert!(unresearched
+        .results
+        .iter()
+        .all(|r| r.entity.roll_count_used <= 14));
+}
+
+struct FakeResearch;
+
+impl ResearchSource for FakeResearch {
+    fn get_research_level(&self, species: u16) -> ResearchLevel {
+        if species == Species::Pikachu as u16 {
+            ResearchLevel::Perfect
+        } else {
+            ResearchLevel::None
+        }
+    }
+
+    fn has_shiny_charm(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn profile_from_research_source() {
+    let profile = ShinyRollProfile::from_research(&FakeResearch);
+    assert_eq!(
+        profile.get_rolls(Species::Pikachu as u16, SpawnType::Regular),
+        4
+    );
+    assert_eq!(
+        profile.get_rolls(Species::Raichu as u16, SpawnType::Regular),
+        1
+    );
+}
+
+// Encrypts a block the way the game stores it. Objects get their length and arrays their entry
+// count and type from `header`.
+fn encode_block(key: u32, type_code: u8, header: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut xor = BlockXor::new(key);
+    let mut block = key.to_le_bytes().to_vec();
+    block.push(type_code ^ xor.next_u8());
+    block.extend(header.iter().chain(data).map(|b| b ^ xor.next_u8()));
+    block
+}
+
+fn get_fixture() -> Vec<u8> {
+    let mut data = encode_block(0x0001_0000, 2, &[], &[]);
+    data.extend(encode_block(0x1234_5678, 10, &[], &600u32.to_le_bytes()));
+    data.extend(encode_block(
+        0xDEAD_BEEF,
+        4,
+        &3u32.to_le_bytes(),
+        &[1, 2, 3],
+    ));
+    let mut array = 2u32.to_le_bytes().to_vec();
+    array.push(9);
+    data.extend(encode_block(0x0BAD_F00D, 5, &array, &[1, 0, 2, 0]));
+    data
+}
+
+#[test]
+fn save_blocks_are_validated() {
+    let data = get_fixture();
+    assert_eq!(save_util::get_block_count(&data), Ok(4));
+
+    let profile = save_util::get_profile(data.clone()).unwrap();
+    assert_eq!(profile.default_level, ResearchLevel::None);
+    assert!(!profile.shiny_charm);
+    assert_eq!(
+        profile.get_rolls(Species::Pikachu as u16, SpawnType::Regular),
+        1
+    );
+
+    assert_eq!(
+        save_util::get_profile(vec![]).unwrap_err(),
+        SaveError::Empty
+    );
+    assert_eq!(
+        save_util::get_profile(data[..data.len() - 1].to_vec()).unwrap_err(),
+        SaveError::Truncated { offset: 36 }
+    );
+    assert_eq!(
+        save_util::get_block_count(&encode_block(0x1234_5678, 42, &[], &[0; 4])),
+        Err(SaveError::InvalidType {
+            key: 0x1234_5678,
+            type_code: 42
+        })
+    );
+}
@@ -0,0 +1,20 @@
+use permute_mmo_rs::util::size_util::{get_height_ratio, get_weight_ratio, SizeClass};
+
+#[test]
+fn size_class_thresholds() {
+    assert_eq!(SizeClass::from(0), SizeClass::XXXS);
+    assert_eq!(SizeClass::from(24), SizeClass::XXS);
+    assert_eq!(SizeClass::from(25), SizeClass::XS);
+    assert_eq!(SizeClass::from(100), SizeClass::AV);
+    assert_eq!(SizeClass::from(231), SizeClass::XXL);
+    assert_eq!(SizeClass::from(255), SizeClass::XXXL);
+    assert_eq!(SizeClass::XXXL.to_string(), "XXXL");
+}
+
+#[test]
+fn size_ratios() {
+    assert!((get_height_ratio(0) - 0.6).abs() < 1e-6);
+    assert!((get_height_ratio(255) - 1.4).abs() < 1e-6);
+    assert!((get_weight_ratio(0) - 0.8).abs() < 1e-6);
+    assert!((get_weight_ratio(255) - 1.2).abs() < 1e-6);
+}
@@ -0,0 +1,108 @@
+use permute_mmo_rs::permutation::{Advance, AdvanceType};
+use permute_mmo_rs::permuter;
+use permute_mmo_rs::util::SpawnInfo;
+use permute_mmo_rs::SpawnSlot;
+
+#[test]
+fn slot_replay_matches_count_replay() {
+    let seed = 1911689355633755303u64;
+    let spawner = SpawnInfo::get_mmo(0xECBF77B8F7302126, 9, 0x9D713CCF138FD43C, 7);
+    let mut result = permuter::permute(spawner.clone(), seed, 15, None);
+    let seq = [
+        AdvanceType::A1,
+        AdvanceType::A1,
+        AdvanceType::A2,
+        AdvanceType::A4,
+        AdvanceType::CR,
+        AdvanceType::A2,
+        AdvanceType::A2,
+    ]
+    .map(Advance::from);
+
+    let steps = Advance::run_forwards(&mut result, &seq, seed);
+    let mut result = permuter::permute(spawner, seed, 15, None);
+    let slot_steps = Advance::run_forwards_slots(&mut result, &seq, seed);
+    assert_eq!(steps.len(), slot_steps.len());
+
+    for (step, slot_step) in steps.iter().zip(slot_steps.iter()) {
+        let mut expect = step.state.get_state().chars().collect::<Vec<_>>();
+        let mut actual = slot_step.state.get_state().chars().collect::<Vec<_>>();
+        expect.sort_unstable();
+        actual.sort_unstable();
+        assert_eq!(expect, actual);
+        assert_eq!(step.seed, slot_step.seed);
+        assert_eq!(step.state.count, slot_step.state.count);
+    }
+
+    let last = slot_steps.last().unwrap();
+    assert!(last
+        .state
+        .occupants()
+        .any(|o| o.entity.is_shiny && o.entity.index == 2));
+}
+
+#[test]
+fn knockouts_track_slot_identity() {
+    let seed = 0xA5D779D8831721FD;
+    let spawner = SpawnInfo::get_mmo(0x7FA3A1DE69BD271E, 10, 0x44182B854CD3745D, 6);
+    let mut result = permuter::permute(spawner, seed, 15, None);
+    let steps = Advance::run_forwards_slots(&mut result, &[Advance::from(AdvanceType::A1)], seed);
+    let before = &steps[0].state;
+    let removed = before.get_removed_by(&Advance::from(AdvanceType::A1));
+    assert_eq!(removed.len(), 1);
+
+    let knocked_out = before.slots[removed[0]].occupant().unwrap();
+    let after = &steps[1].state;
+    assert!(matches!(after.slots[removed[0]], SpawnSlot::Empty));
+    assert!(!after.occupants().any(|o| o.is_same_entity(knocked_out)));
+    assert_eq!(after.alive(), before.alive() - 1);
+}
+
+#[test]
+fn replay_applies_every_advance() {
+    let seed = 1911689355633755303u64;
+    let spawner = SpawnInfo::get_mmo(0xECBF77B8F7302126, 9, 0x9D713CCF138FD43C, 7);
+    let mut meta = permuter::permute(spawner.clone(), seed, 1, None);
+    meta.spawner = spawner;
+
+    let mut advances = vec![Advance::knockout_aggressive(1); 5];
+    advances.push(Advance::ghosts(1));
+    advances.push(Advance::clear_remaining());
+    let steps = Advance::run_forwards(&mut meta, &advances, seed);
+
+    let expected = [
+        ("RG", "AAAA", 5),
+        ("A1", "AAAX", 5),
+        ("RG", "AAAA", 4),
+        ("A1", "AAAX", 4),
+        ("RG", "AAAA", 3),
+        ("A1", "AAAX", 3),
+        ("RG", "AAAA", 2),
+        ("A1", "AAAX", 2),
+        ("RG", "AAAA", 1),
+        ("A1", "AAAX", 1),
+        ("RG", "AAAA", 0),
+        ("G1", "~???", 0),
+        ("CR", "XXXX", 7),
+        ("RG", "aaaa", 3),
+    ];
+    let actual = steps
+        .iter()
+        .map(|s| (s.step.short_code(), s.state.get_state(), s.state.count))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        actual,
+        expected
+            .iter()
+            .map(|(code, state, count)| (code.to_string(), state.to_string(), *count))
+            .collect::<Vec<_>>()
+    );
+
+    // Knockouts keep the seed until the next respawn, while ghosts reseed immediately.
+    for pair in steps.windows(2) {
+        match pair[1].step.short_code().as_str() {
+            "A1" | "CR" => assert_eq!(pair[1].seed, pair[0].seed),
+            _ => assert_ne!(pair[1].seed, pair[0].seed),
+        }
+    }
+}
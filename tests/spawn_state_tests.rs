@@ -0,0 +1,143 @@
+use permute_mmo_rs::generation::ShinyRollProfile;
+use permute_mmo_rs::permuter;
+use permute_mmo_rs::util::SpawnInfo;
+use permute_mmo_rs::{SpawnState, SpawnStateError};
+
+const BASE_TABLE: u64 = 0x7FA3A1DE69BD271E;
+const BONUS_TABLE: u64 = 0x44182B854CD3745D;
+
+fn permute_from(wave: usize, state: &str) -> Result<Vec<u64>, SpawnStateError> {
+    let spawner = SpawnInfo::get_mmo(BASE_TABLE, 10, BONUS_TABLE, 6);
+    let result = permuter::permute_from_state(
+        spawner,
+        wave,
+        0x1234_5678,
+        state.parse().unwrap(),
+        6,
+        Some(|_, _| true),
+        ShinyRollProfile::default(),
+    )?;
+    Ok(result.results.iter().map(|r| r.entity.table).collect())
+}
+
+#[test]
+fn parse_glyph_state() {
+    let state: SpawnState = "aAB~X? 5".parse().unwrap();
+    assert_eq!(state.count, 5);
+    assert_eq!(state.max_alive, 6);
+    assert_eq!(state.alive_alpha, 1);
+    assert_eq!(state.alive_aggressive, 2);
+    assert_eq!(state.alive_beta, 1);
+    assert_eq!(state.ghost, 1);
+    assert_eq!(state.dead, 2);
+    assert_eq!(state.get_state(), "aAB~X?");
+
+    let knocked = state.knockout_aggressive(2);
+    let reparsed: SpawnState = format!("{} {}", knocked.get_state(), knocked.count)
+        .parse()
+        .unwrap();
+    assert_eq!(reparsed.get_state(), knocked.get_state());
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(
+        "".parse::<SpawnState>().unwrap_err(),
+        SpawnStateError::NoSlots
+    );
+    assert_eq!(
+        "AAAA".parse::<SpawnState>().unwrap_err(),
+        SpawnStateError::MissingCount
+    );
+    assert_eq!(
+        "AAAA x".parse::<SpawnState>().unwrap_err(),
+        SpawnStateError::InvalidCount("x".to_string())
+    );
+    assert_eq!(
+        "AAZA 3".parse::<SpawnState>().unwrap_err(),
+        SpawnStateError::InvalidGlyph('Z')
+    );
+    assert_eq!(
+        "~~~~ 3".parse::<SpawnState>().unwrap_err(),
+        SpawnStateError::TooManyGhosts {
+            ghost: 4,
+            max_ghosts: 3
+        }
+    );
+}
+
+#[test]
+fn validate_inconsistent_state() {
+    let mut state = SpawnState::get(10, 4);
+    assert!(state.validate().is_ok());
+    state.alive_aggressive = 2;
+    assert_eq!(
+        state.validate(),
+        Err(SpawnStateError::SlotOverflow {
+            used: 6,
+            max_alive: 4
+        })
+    );
+}
+
+#[test]
+fn knockout_any_removes_every_behavior() {
+    // Aggressive first, then skittish, then oblivious. This used to cap each count by the wrong
+    // behaviour, so "AOOB" lost only the aggressive one for 4 knockouts.
+    let state: SpawnState = "AOOB 0".parse().unwrap();
+    let knocked = state.knockout_any(4);
+    assert_eq!(knocked.alive(), 0);
+    assert_eq!(knocked.get_state(), "XXXX");
+
+    let knocked = state.knockout_any(3);
+    assert_eq!(knocked.alive_aggressive, 0);
+    assert_eq!(knocked.alive_beta, 0);
+    assert_eq!(knocked.alive_oblivious, 1);
+    assert_eq!(knocked.dead, 3);
+
+    // Previously removed nothing, tripping the assertion in remove.
+    let oblivious: SpawnState = "OO 0".parse().unwrap();
+    assert_eq!(oblivious.knockout_any(2).dead, 2);
+}
+
+#[test]
+fn unknown_slots_count_as_alive() {
+    let unknown: SpawnState = "AB?? 0".parse().unwrap();
+    assert_eq!(unknown.unknown(), 2);
+    assert_eq!(unknown.alive(), 4);
+}
+
+#[test]
+fn state_is_applied_to_chosen_wave() {
+    let base = permute_from(0, "AXXX 6").unwrap();
+    assert!(!base.is_empty());
+    assert!(base.contains(&BASE_TABLE));
+
+    let bonus = permute_from(1, "AXXX 2").unwrap();
+    assert!(!bonus.is_empty());
+    assert!(bonus.iter().all(|table| *table == BONUS_TABLE));
+}
+
+#[test]
+fn state_is_validated_against_spawner() {
+    assert_eq!(permute_from(2, "AAAA 0"), Err(SpawnStateError::NoWave(2)));
+    assert_eq!(
+        permute_from(0, "AAA 3"),
+        Err(SpawnStateError::SlotCountMismatch {
+            max_alive: 3,
+            min: 4,
+            max: 4
+        })
+    );
+    assert_eq!(
+        permute_from(1, "AAAA 7"),
+        Err(SpawnStateError::CountExceedsWave {
+            count: 7,
+            wave_count: 6
+        })
+    );
+    assert_eq!(
+        permute_from(0, "AA?? 5"),
+        Err(SpawnStateError::UnknownSlots(2))
+    );
+}
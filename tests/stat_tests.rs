@@ -0,0 +1,49 @@
+use permute_mmo_rs::util::stat_util::{get_ganbaru_bias, get_ganbaru_stat, get_stats};
+use pkhex_rs::Species;
+
+const PIKACHU: u16 = Species::Pikachu as u16;
+const HARDY: u8 = 0;
+const ADAMANT: u8 = 3;
+const TIMID: u8 = 10;
+
+#[test]
+fn effort_level_bias_from_ivs() {
+    assert_eq!(get_ganbaru_bias(0), 0);
+    assert_eq!(get_ganbaru_bias(19), 0);
+    assert_eq!(get_ganbaru_bias(20), 1);
+    assert_eq!(get_ganbaru_bias(26), 2);
+    assert_eq!(get_ganbaru_bias(31), 3);
+}
+
+#[test]
+fn effort_level_stat_bonus() {
+    assert_eq!(get_ganbaru_stat(100, 31, 0, 50), 32);
+    assert_eq!(get_ganbaru_stat(35, 31, 0, 50), 19);
+    assert_eq!(get_ganbaru_stat(100, 0, 0, 50), 0);
+    assert_eq!(get_ganbaru_stat(100, 20, 0, 50), 16);
+    assert_eq!(get_ganbaru_stat(100, 0, 10, 50), 200);
+    // Effort levels add to the IV bias and are capped at the last multiplier.
+    assert_eq!(get_ganbaru_stat(100, 31, 10, 50), 200);
+    assert_eq!(get_ganbaru_stat(100, 31, 1, 50), 56);
+}
+
+// Expected values are PKHeX's PA8 stats for the same species, level, nature and IVs.
+#[test]
+fn stats_from_base_stats() {
+    assert_eq!(
+        get_stats(PIKACHU, 0, 50, HARDY, &[31; 6]),
+        [121, 97, 73, 89, 89, 150]
+    );
+    assert_eq!(
+        get_stats(PIKACHU, 0, 50, ADAMANT, &[31; 6]),
+        [121, 106, 73, 80, 89, 150]
+    );
+    assert_eq!(
+        get_stats(PIKACHU, 0, 50, TIMID, &[0, 20, 26, 31, 10, 31]),
+        [102, 76, 68, 89, 66, 165]
+    );
+    assert_eq!(
+        get_stats(PIKACHU, 0, 1, HARDY, &[0; 6]),
+        [36, 37, 27, 34, 34, 61]
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "runtime-tables")]
+
+use permute_mmo_rs::generation::{encounter_table, spawn_generator, ShinyRollProfile, SpawnType};
+use permute_mmo_rs::util::json_decoder::TableError;
+use pkhex_rs::Species;
+
+const TABLE: u64 = 0x7FA3A1DE69BD271E;
+
+#[test]
+fn loaded_tables_merge_and_override() {
+    let json = r#"{
+        "0x7FA3A1DE69BD271E": [
+            { "slot": 1, "name": "Raichu", "alpha": false, "level": [10, 12], "ivs": 0 }
+        ],
+        "0x0000000100000BAD": [
+            { "slot": 50, "name": "Basculin-2", "alpha": false, "level": [5, 8], "ivs": 0 },
+            { "slot": 1, "name": "Basculin-2", "alpha": true, "level": [10, 12], "ivs": 3 }
+        ]
+    }"#;
+    assert!(
+        !encounter_table::get_tables_containing(Species::Basculin as u16, 2)
+            .contains(&0x1_0000_0BAD)
+    );
+    let hashes = spawn_generator::load_tables(json).unwrap();
+    assert_eq!(hashes, vec![0x1_0000_0BAD, TABLE]);
+
+    let table = encounter_table::get_table(TABLE).unwrap();
+    assert_eq!(table.len(), 1);
+    assert_eq!(table[0].species, Species::Raichu as u16);
+
+    let added = encounter_table::get_table(0x1_0000_0BAD).unwrap();
+    assert_eq!(added[0].form, 2);
+    assert!(encounter_table::get_table_hashes().contains(&0x1_0000_0BAD));
+    assert!(
+        encounter_table::get_tables_containing(Species::Basculin as u16, 2)
+            .contains(&0x1_0000_0BAD)
+    );
+}
+
+#[test]
+fn malformed_tables_are_rejected() {
+    let bad_hash = r#"{ "7FA3": [] }"#;
+    assert_eq!(
+        spawn_generator::load_tables(bad_hash),
+        Err(TableError::InvalidHash("7FA3".to_string()))
+    );
+
+    let bad_species = r#"{ "0x1234": [
+        { "slot": 1, "name": "Pikachu", "alpha": false, "ivs": 0 },
+        { "slot": 1, "name": "NotAMon", "alpha": false, "ivs": 0 }
+    ] }"#;
+    let err = spawn_generator::load_tables(bad_species).unwrap_err();
+    assert!(matches!(err, TableError::InvalidSlot { index: 1, .. }));
+    assert!(err.to_string().contains("NotAMon"));
+
+    let bad_json = r#"{ "0x1234": [ { "slot": 1 } ] }"#;
+    assert!(matches!(
+        spawn_generator::load_tables(bad_json),
+        Err(TableError::Json(_))
+    ));
+    assert!(encounter_table::get_table(0x1234).is_none());
+
+    assert!(matches!(
+        spawn_generator::load_table_file("does/not/exist.json"),
+        Err(TableError::Io { .. })
+    ));
+}
+
+#[test]
+fn loaded_outbreak_tables_provide_levels() {
+    let species = Species::Growlithe as u16;
+    let table = spawn_generator::get_outbreak_table(species, 1);
+    assert_eq!(
+        spawn_generator::get_spawn_table(table).slots[0].level,
+        Some([60, 62])
+    );
+
+    let json = r#"{
+        "Growlithe-1": [
+            { "slot": 100, "name": "Growlithe-1", "alpha": false, "level": [12, 15], "ivs": 0 },
+            { "slot": 1, "name": "Growlithe-1", "alpha": true, "level": [22, 25], "ivs": 3 }
+        ]
+    }"#;
+    assert_eq!(spawn_generator::load_tables(json).unwrap(), vec![table]);
+    assert!(!encounter_table::get_table_hashes().contains(&table));
+
+    let profile = ShinyRollProfile::default();
+    for i in 0..50 {
+        let entity = spawn_generator::generate(
+            0,
+            i,
+            i as u64,
+            0,
+            table,
+            SpawnType::Outbreak,
+            &profile,
+            false,
+        )
+        .unwrap();
+        assert_eq!(entity.form, 1);
+        let range = if entity.is_alpha { 22..=25 } else { 12..=15 };
+        assert!(range.contains(&entity.level));
+    }
+}
+
+#[test]
+fn loaded_outbreak_tables_without_levels_use_outbreak_levels() {
+    let species = Species::Bidoof as u16;
+    let table = spawn_generator::get_outbreak_table(species, 0);
+    let json = r#"{
+        "Bidoof": [
+            { "slot": 100, "name": "Bidoof", "alpha": false, "ivs": 0 },
+            { "slot": 1, "name": "Bidoof", "alpha": true, "ivs": 3 }
+        ]
+    }"#;
+    assert_eq!(spawn_generator::load_tables(json).unwrap(), vec![table]);
+
+    let slots = encounter_table::get_table(table).unwrap();
+    assert_eq!(slots[0].level, Some([12, 14]));
+    assert_eq!(slots[1].level, Some([27, 29]));
+}
@@ -0,0 +1,72 @@
+use permute_mmo_rs::util::table_validator::{self, TableIssue};
+
+#[test]
+fn built_in_tables_are_valid() {
+    let issues = table_validator::validate_tables();
+    assert!(issues.is_empty(), "{:#?}", issues);
+}
+
+#[cfg(feature = "runtime-tables")]
+#[test]
+fn resource_json_is_valid() {
+    let json = include_str!("../resources/mmo_es.json");
+    let issues = table_validator::validate_json(json).unwrap();
+    assert!(issues.is_empty(), "{:#?}", issues);
+}
+
+#[cfg(feature = "runtime-tables")]
+#[test]
+fn invalid_tables_are_reported() {
+    let json = r#"{
+        "0x1234": [
+            { "slot": 0, "name": "Pikachu", "alpha": false, "level": [10, 5], "ivs": 0 },
+            { "slot": 1, "name": "Pikachu-99", "alpha": true, "level": [10, 12], "ivs": 2 },
+            { "slot": 1, "name": "NotAMon", "alpha": false, "level": [10, 12], "ivs": 0 },
+            { "slot": 1, "name": "Raichu", "alpha": false, "ivs": 0 }
+        ],
+        "0x1234": [],
+        "Pikachu": [
+            { "slot": 1, "name": "Pikachu", "alpha": false, "ivs": 0 }
+        ],
+        "0xZZ": []
+    }"#;
+    let issues = table_validator::validate_json(json).unwrap();
+    let table = "0x1234".to_string();
+    assert_eq!(
+        issues,
+        vec![
+            TableIssue::ZeroRate {
+                table: table.clone(),
+                index: 0
+            },
+            TableIssue::UnorderedLevel {
+                table: table.clone(),
+                index: 0,
+                min: 10,
+                max: 5
+            },
+            TableIssue::AlphaIvs {
+                table: table.clone(),
+                index: 1,
+                flawless_ivs: 2
+            },
+            TableIssue::MissingForm {
+                table: table.clone(),
+                index: 1,
+                species: 25,
+                form: 99
+            },
+            TableIssue::InvalidSpecies {
+                table: table.clone(),
+                index: 2,
+                reason: "No species found for NotAMon".to_string()
+            },
+            TableIssue::MissingLevel {
+                table: table.clone(),
+                index: 3
+            },
+            TableIssue::DuplicateHash(table),
+            TableIssue::InvalidKey("0xZZ".to_string()),
+        ]
+    );
+}
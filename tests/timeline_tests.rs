@@ -0,0 +1,31 @@
+use permute_mmo_rs::permutation::{Advance, AdvanceType};
+use permute_mmo_rs::permuter;
+use permute_mmo_rs::util::{timeline, SpawnInfo};
+
+#[test]
+fn timeline_lists_every_step() {
+    let seed = 1911689355633755303u64;
+    let spawner = SpawnInfo::get_mmo(0xECBF77B8F7302126, 9, 0x9D713CCF138FD43C, 7);
+    let mut result = permuter::permute(spawner, seed, 15, None);
+    let seq = [
+        AdvanceType::A1,
+        AdvanceType::A1,
+        AdvanceType::A2,
+        AdvanceType::A4,
+        AdvanceType::CR,
+        AdvanceType::A2,
+        AdvanceType::A2,
+    ]
+    .map(Advance::from);
+    let steps = Advance::run_forwards(&mut result, &seq, seed);
+
+    let lines = timeline::get_lines(&steps, &result.results);
+    assert_eq!(lines.len(), steps.len() + 2);
+    assert!(lines[0].starts_with("# "));
+    assert!(lines[2].contains("[A][A][A][A]"));
+    assert!(lines[2].contains(&format!("{:0>16X}", steps[0].seed)));
+
+    let html = timeline::get_html(&steps, &result.results);
+    assert_eq!(html.matches("<tr>").count(), steps.len() + 1);
+    assert!(html.contains("slot-aggressive"));
+}